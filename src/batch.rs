@@ -0,0 +1,199 @@
+//! Streaming annotation of CSV/TSV data with phonetic key columns.
+
+use std::fmt;
+use std::io::{Read, Write};
+
+use csv::{ByteRecord, ReaderBuilder, WriterBuilder};
+
+use crate::encoder::PhoneticEncoder;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColumnSelector {
+    /// A column named in the header row.
+    Name(String),
+    /// A 0-based column index.
+    Index(usize),
+}
+
+impl From<&str> for ColumnSelector {
+    fn from(name: &str) -> Self {
+        ColumnSelector::Name(name.to_string())
+    }
+}
+
+impl From<String> for ColumnSelector {
+    fn from(name: String) -> Self {
+        ColumnSelector::Name(name)
+    }
+}
+
+impl From<usize> for ColumnSelector {
+    fn from(index: usize) -> Self {
+        ColumnSelector::Index(index)
+    }
+}
+
+impl fmt::Display for ColumnSelector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColumnSelector::Name(name) => write!(f, "{:?}", name),
+            ColumnSelector::Index(index) => write!(f, "{}", index),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum BatchError {
+    /// The input has no column matching the selector.
+    MissingColumn(ColumnSelector),
+    Csv(csv::Error),
+}
+
+impl fmt::Display for BatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BatchError::MissingColumn(column) => write!(f, "no column {} in the input", column),
+            BatchError::Csv(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for BatchError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BatchError::MissingColumn(_) => None,
+            BatchError::Csv(err) => Some(err),
+        }
+    }
+}
+
+impl From<csv::Error> for BatchError {
+    fn from(err: csv::Error) -> Self {
+        BatchError::Csv(err)
+    }
+}
+
+impl From<std::io::Error> for BatchError {
+    fn from(err: std::io::Error) -> Self {
+        BatchError::Csv(err.into())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BatchSummary {
+    pub rows: usize,
+    /// Rows where at least one encoder failed.
+    pub rows_with_errors: usize,
+}
+
+/// Copies delimited rows from a reader to a writer, appending a `<column>_<encoder>` column
+/// per encoder and a `<column>_error` column. An encoder that rejects a value leaves its
+/// column empty and its error in the error column; it never stops the batch.
+pub struct BatchAnnotator {
+    column: ColumnSelector,
+    encoders: Vec<Box<dyn PhoneticEncoder>>,
+    delimiter: u8,
+}
+
+impl BatchAnnotator {
+    pub fn new(column: impl Into<ColumnSelector>, encoders: Vec<Box<dyn PhoneticEncoder>>) -> Self {
+        BatchAnnotator {
+            column: column.into(),
+            encoders,
+            delimiter: b',',
+        }
+    }
+
+    /// Sets the field delimiter used for both input and output, e.g. `b'\t'` for TSV.
+    pub fn with_delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Annotates every row of `reader`, which must start with a header row. Rows are streamed,
+    /// so memory use does not grow with the input.
+    pub fn annotate<R: Read, W: Write>(
+        &self,
+        reader: R,
+        writer: W,
+    ) -> Result<BatchSummary, BatchError> {
+        let mut reader = ReaderBuilder::new()
+            .delimiter(self.delimiter)
+            .flexible(true)
+            .from_reader(reader);
+        let mut writer = WriterBuilder::new()
+            .delimiter(self.delimiter)
+            .flexible(true)
+            .from_writer(writer);
+
+        let mut headers = reader.byte_headers()?.clone();
+        let width = headers.len();
+        let index = self.column_index(&headers)?;
+        let column = String::from_utf8_lossy(&headers[index]).into_owned();
+        for encoder in &self.encoders {
+            headers.push_field(format!("{}_{}", column, encoder.name()).as_bytes());
+        }
+        headers.push_field(format!("{}_error", column).as_bytes());
+        writer.write_byte_record(&headers)?;
+
+        let mut summary = BatchSummary::default();
+        let mut record = ByteRecord::new();
+        let mut errors = Vec::new();
+
+        while reader.read_byte_record(&mut record)? {
+            let value = String::from_utf8_lossy(record.get(index).unwrap_or_default());
+            let value = value.trim();
+
+            errors.clear();
+            let mut codes = Vec::with_capacity(self.encoders.len());
+            for encoder in &self.encoders {
+                match encoder.encode(value) {
+                    Ok(code) => codes.push(code),
+                    Err(err) => {
+                        codes.push(String::new());
+                        errors.push(format!("{}: {}", encoder.name(), err));
+                    }
+                }
+            }
+
+            // Pad short rows and cut long ones so the added columns line up with their
+            // headers. Cut fields are reported, as they are lost from the output.
+            if record.len() > width {
+                errors.push(format!(
+                    "{} fields beyond the {} of the header dropped",
+                    record.len() - width,
+                    width
+                ));
+                record.truncate(width);
+            }
+            while record.len() < width {
+                record.push_field(b"");
+            }
+            for code in &codes {
+                record.push_field(code.as_bytes());
+            }
+            record.push_field(errors.join("; ").as_bytes());
+            writer.write_byte_record(&record)?;
+
+            summary.rows += 1;
+            if !errors.is_empty() {
+                summary.rows_with_errors += 1;
+            }
+        }
+
+        writer.flush()?;
+
+        Ok(summary)
+    }
+
+    fn column_index(&self, headers: &ByteRecord) -> Result<usize, BatchError> {
+        let index = match &self.column {
+            ColumnSelector::Name(name) => {
+                headers.iter().position(|header| header == name.as_bytes())
+            }
+            ColumnSelector::Index(index) => Some(*index).filter(|&i| i < headers.len()),
+        };
+
+        index.ok_or_else(|| BatchError::MissingColumn(self.column.clone()))
+    }
+}
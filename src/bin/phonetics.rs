@@ -0,0 +1,305 @@
+//! Command-line interface: encodes names, compares them pairwise or groups them by code.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::io::{self, BufRead, BufWriter, Write};
+use std::process::ExitCode;
+
+use phonetics::{BatchAnnotator, ColumnSelector, ENCODER_NAMES, PhoneticEncoder, encoder_by_name};
+
+const USAGE: &str = "\
+Usage: phonetics <COMMAND> [OPTIONS] [NAME...]
+
+Commands:
+  encode   Print each name with its code
+  compare  Print whether each pair of names matches
+  group    Print the names sharing each code
+  annotate Copy CSV from stdin to stdout, adding a code column per algorithm
+           and an error column; requires --column
+
+Names are taken from the arguments, or read from stdin one per line.
+
+Options:
+  -a, --algorithm <NAME>  Algorithm to use [default: metaphone]; annotate
+                          accepts it more than once
+  -c, --column <COLUMN>   Read stdin as CSV and take names from this column,
+                          given as a header, or a 0-based index if numeric
+  -d, --delimiter <CHAR>  Field delimiter for CSV input and output [default: ,]
+      --list              List the available algorithms
+  -h, --help              Print this help
+";
+
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+enum Command {
+    Encode,
+    Compare,
+    Group,
+    Annotate,
+}
+
+struct Args {
+    command: Command,
+    algorithms: Vec<String>,
+    column: Option<ColumnSelector>,
+    delimiter: u8,
+    names: Vec<String>,
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(code) => code,
+        Err(err) => {
+            eprintln!("phonetics: {}", err);
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run() -> Result<ExitCode> {
+    let Some(args) = parse_args()? else {
+        return Ok(ExitCode::SUCCESS);
+    };
+
+    let encoders = args
+        .algorithms
+        .iter()
+        .map(|name| {
+            encoder_by_name(name).ok_or_else(|| {
+                format!(
+                    "unknown algorithm {:?}, expected one of: {}",
+                    name,
+                    ENCODER_NAMES.join(", ")
+                )
+            })
+        })
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    let ok = match args.command {
+        Command::Encode => run_on_names(&args, encoders, encode)?,
+        Command::Compare => run_on_names(&args, encoders, compare)?,
+        Command::Group => run_on_names(&args, encoders, group)?,
+        Command::Annotate => annotate(&args, encoders)?,
+    };
+
+    Ok(if ok {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+/// Returns `None` when the invocation only asked for help or the algorithm list.
+fn parse_args() -> Result<Option<Args>> {
+    use lexopt::prelude::*;
+
+    let mut command = None;
+    let mut algorithms = Vec::new();
+    let mut column = None;
+    let mut delimiter = b',';
+    let mut names = Vec::new();
+
+    let mut parser = lexopt::Parser::from_env();
+    while let Some(arg) = parser.next()? {
+        match arg {
+            Short('a') | Long("algorithm") => algorithms.push(parser.value()?.string()?),
+            Short('c') | Long("column") => column = Some(parse_column(parser.value()?.string()?)),
+            Short('d') | Long("delimiter") => {
+                delimiter = parse_delimiter(&parser.value()?.string()?)?
+            }
+            Long("list") => {
+                for name in ENCODER_NAMES {
+                    println!("{}", name);
+                }
+                return Ok(None);
+            }
+            Short('h') | Long("help") => {
+                print!("{}", USAGE);
+                return Ok(None);
+            }
+            Value(value) if command.is_none() => command = Some(parse_command(&value.string()?)?),
+            Value(value) => names.push(value.string()?),
+            _ => return Err(arg.unexpected().into()),
+        }
+    }
+
+    let command = command.ok_or_else(|| format!("missing command\n\n{}", USAGE))?;
+    if algorithms.is_empty() {
+        algorithms.push("metaphone".to_string());
+    }
+
+    Ok(Some(Args {
+        command,
+        algorithms,
+        column,
+        delimiter,
+        names,
+    }))
+}
+
+fn parse_command(value: &str) -> Result<Command> {
+    match value {
+        "encode" => Ok(Command::Encode),
+        "compare" => Ok(Command::Compare),
+        "group" => Ok(Command::Group),
+        "annotate" => Ok(Command::Annotate),
+        _ => Err(format!("unknown command {:?}\n\n{}", value, USAGE).into()),
+    }
+}
+
+fn parse_column(value: String) -> ColumnSelector {
+    match value.parse() {
+        Ok(index) => ColumnSelector::Index(index),
+        Err(_) => ColumnSelector::Name(value),
+    }
+}
+
+fn parse_delimiter(value: &str) -> Result<u8> {
+    match value {
+        "\\t" | "tab" => Ok(b'\t'),
+        _ if value.len() == 1 => Ok(value.as_bytes()[0]),
+        _ => Err(format!(
+            "delimiter must be a single ASCII character, got {:?}",
+            value
+        )
+        .into()),
+    }
+}
+
+fn read_names(args: &Args) -> Result<Vec<String>> {
+    if !args.names.is_empty() {
+        return Ok(args.names.clone());
+    }
+
+    let stdin = io::stdin().lock();
+
+    let Some(column) = &args.column else {
+        let mut names = Vec::new();
+        for line in stdin.lines() {
+            let line = line?;
+            let name = line.trim();
+            if !name.is_empty() {
+                names.push(name.to_string());
+            }
+        }
+        return Ok(names);
+    };
+
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(args.delimiter)
+        .from_reader(stdin);
+    let headers = reader.headers()?;
+    let index = match column {
+        ColumnSelector::Name(name) => headers.iter().position(|header| header == name),
+        ColumnSelector::Index(index) => Some(*index).filter(|&i| i < headers.len()),
+    }
+    .ok_or_else(|| format!("no column {} in the input", column))?;
+
+    let mut names = Vec::new();
+    for record in reader.records() {
+        let name = record?.get(index).unwrap_or_default().trim().to_string();
+        if !name.is_empty() {
+            names.push(name);
+        }
+    }
+
+    Ok(names)
+}
+
+type NamesCommand = fn(&dyn PhoneticEncoder, &[String], &mut dyn Write) -> Result<bool>;
+
+/// Runs a command that takes a single encoder over the names given on the command line or stdin.
+fn run_on_names(
+    args: &Args,
+    mut encoders: Vec<Box<dyn PhoneticEncoder>>,
+    command: NamesCommand,
+) -> Result<bool> {
+    if encoders.len() > 1 {
+        return Err("only annotate accepts more than one algorithm".into());
+    }
+    let encoder = encoders.remove(0);
+    let names = read_names(args)?;
+
+    let mut out = BufWriter::new(io::stdout().lock());
+    let ok = command(encoder.as_ref(), &names, &mut out)?;
+    out.flush()?;
+
+    Ok(ok)
+}
+
+fn annotate(args: &Args, encoders: Vec<Box<dyn PhoneticEncoder>>) -> Result<bool> {
+    let column = args.column.clone().ok_or("annotate requires --column")?;
+    let summary = BatchAnnotator::new(column, encoders)
+        .with_delimiter(args.delimiter)
+        .annotate(io::stdin().lock(), BufWriter::new(io::stdout().lock()))?;
+
+    Ok(summary.rows_with_errors == 0)
+}
+
+/// Reports an encoding error on stderr; the command carries on with the other names.
+fn report(name: &str, err: impl Error) {
+    eprintln!("phonetics: {:?}: {}", name, err);
+}
+
+fn encode(encoder: &dyn PhoneticEncoder, names: &[String], out: &mut dyn Write) -> Result<bool> {
+    let mut ok = true;
+
+    for name in names {
+        match encoder.encode(name) {
+            Ok(code) => writeln!(out, "{}\t{}", name, code)?,
+            Err(err) => {
+                report(name, err);
+                ok = false;
+            }
+        }
+    }
+
+    Ok(ok)
+}
+
+fn compare(encoder: &dyn PhoneticEncoder, names: &[String], out: &mut dyn Write) -> Result<bool> {
+    let mut ok = true;
+
+    for (i, name1) in names.iter().enumerate() {
+        for name2 in &names[i + 1..] {
+            match encoder.compare(name1, name2) {
+                Ok(matched) => writeln!(out, "{}\t{}\t{}", name1, name2, matched)?,
+                Err(err) => {
+                    report(&format!("{} / {}", name1, name2), err);
+                    ok = false;
+                }
+            }
+        }
+    }
+
+    Ok(ok)
+}
+
+/// Prints one line per code, in order of first appearance, listing every name with that code.
+fn group(encoder: &dyn PhoneticEncoder, names: &[String], out: &mut dyn Write) -> Result<bool> {
+    let mut ok = true;
+    let mut groups: Vec<(String, Vec<&str>)> = Vec::new();
+    let mut index: HashMap<String, usize> = HashMap::new();
+
+    for name in names {
+        match encoder.encode(name) {
+            Ok(code) => {
+                let i = *index.entry(code.clone()).or_insert_with(|| {
+                    groups.push((code, Vec::new()));
+                    groups.len() - 1
+                });
+                groups[i].1.push(name);
+            }
+            Err(err) => {
+                report(name, err);
+                ok = false;
+            }
+        }
+    }
+
+    for (code, members) in groups {
+        writeln!(out, "{}\t{}", code, members.join("\t"))?;
+    }
+
+    Ok(ok)
+}
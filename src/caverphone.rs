@@ -0,0 +1,218 @@
+use regex::Regex;
+
+use crate::encoder::PhoneticEncoder;
+use crate::error::{PhoneticError, validate_encodable};
+
+const COMMON_START_RULES: &[(&str, &str)] = &[
+    ("^cough", "cou2f"),
+    ("^rough", "rou2f"),
+    ("^tough", "tou2f"),
+    ("^enough", "enou2f"),
+];
+
+const COMMON_CONSONANT_RULES: &[(&str, &str)] = &[
+    ("^gn", "2n"),
+    ("mb$", "m2"),
+    ("cq", "2q"),
+    ("ci", "si"),
+    ("ce", "se"),
+    ("cy", "sy"),
+    ("tch", "2ch"),
+    ("c", "k"),
+    ("q", "k"),
+    ("x", "k"),
+    ("v", "f"),
+    ("dg", "2g"),
+    ("tio", "sio"),
+    ("tia", "sia"),
+    ("d", "t"),
+    ("ph", "fh"),
+    ("b", "p"),
+    ("sh", "s2"),
+    ("z", "s"),
+    ("^[aeiou]", "A"),
+    ("[aeiou]", "3"),
+];
+
+const COMMON_GROUP_RULES: &[(&str, &str)] = &[
+    ("s+", "S"),
+    ("t+", "T"),
+    ("p+", "P"),
+    ("k+", "K"),
+    ("f+", "F"),
+    ("m+", "M"),
+    ("n+", "N"),
+];
+
+// Caverphone 1.0 and 2.0 share most of their rules; the version-specific ones run either side
+// of the letter-group rules.
+const VERSION_ONE_START_RULES: &[(&str, &str)] = &[];
+const VERSION_ONE_VOWEL_RULES: &[(&str, &str)] = &[("3gh3", "3kh3"), ("gh", "22"), ("g", "k")];
+const VERSION_ONE_FINAL_RULES: &[(&str, &str)] = &[
+    ("w3", "W3"),
+    ("wh3", "Wh3"),
+    ("w", "2"),
+    ("^h", "A"),
+    ("h", "2"),
+    ("r3", "R3"),
+    ("r", "2"),
+    ("l3", "L3"),
+    ("l", "2"),
+    ("j", "y"),
+    ("y3", "Y3"),
+    ("y", "2"),
+    ("2", ""),
+    ("3", ""),
+];
+
+const VERSION_TWO_START_RULES: &[(&str, &str)] = &[("^trough", "trou2f")];
+const VERSION_TWO_VOWEL_RULES: &[(&str, &str)] = &[
+    ("j", "y"),
+    ("^y3", "Y3"),
+    ("^y", "A"),
+    ("y", "3"),
+    ("3gh3", "3kh3"),
+    ("gh", "22"),
+    ("g", "k"),
+];
+const VERSION_TWO_FINAL_RULES: &[(&str, &str)] = &[
+    ("w3", "W3"),
+    ("wh3", "Wh3"),
+    ("w$", "3"),
+    ("w", "2"),
+    ("^h", "A"),
+    ("h", "2"),
+    ("r3", "R3"),
+    ("r$", "3"),
+    ("r", "2"),
+    ("l3", "L3"),
+    ("l$", "3"),
+    ("l", "2"),
+    ("2", ""),
+    ("3$", "A"),
+    ("3", ""),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaverphoneVersion {
+    /// Caverphone 1.0, producing 6-character codes.
+    One,
+    /// Caverphone 2.0, producing 10-character codes.
+    Two,
+}
+
+impl CaverphoneVersion {
+    fn code_length(&self) -> usize {
+        match self {
+            CaverphoneVersion::One => 6,
+            CaverphoneVersion::Two => 10,
+        }
+    }
+}
+
+pub struct Caverphone {
+    version: CaverphoneVersion,
+    rules: Vec<(Regex, &'static str)>,
+}
+
+impl Caverphone {
+    pub fn new() -> Self {
+        Self::with_version(CaverphoneVersion::Two)
+    }
+
+    pub fn with_version(version: CaverphoneVersion) -> Self {
+        let (start_rules, vowel_rules, final_rules) = match version {
+            CaverphoneVersion::One => (
+                VERSION_ONE_START_RULES,
+                VERSION_ONE_VOWEL_RULES,
+                VERSION_ONE_FINAL_RULES,
+            ),
+            CaverphoneVersion::Two => (
+                VERSION_TWO_START_RULES,
+                VERSION_TWO_VOWEL_RULES,
+                VERSION_TWO_FINAL_RULES,
+            ),
+        };
+
+        // Caverphone 2.0 drops a final "e" before anything else.
+        let silent_e: &[(&str, &str)] = match version {
+            CaverphoneVersion::One => &[],
+            CaverphoneVersion::Two => &[("e$", "")],
+        };
+
+        let rules = [
+            silent_e,
+            COMMON_START_RULES,
+            start_rules,
+            COMMON_CONSONANT_RULES,
+            vowel_rules,
+            COMMON_GROUP_RULES,
+            final_rules,
+        ]
+        .concat()
+        .into_iter()
+        .map(|(pattern, replacement)| (Regex::new(pattern).unwrap(), replacement))
+        .collect();
+
+        Caverphone { version, rules }
+    }
+
+    pub fn version(&self) -> CaverphoneVersion {
+        self.version
+    }
+
+    pub fn encode(&self, name: &str) -> String {
+        let mut txt: String = name
+            .chars()
+            .filter(|c| c.is_ascii_alphabetic())
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+
+        for (pattern, replacement) in &self.rules {
+            txt = pattern.replace_all(&txt, *replacement).into_owned();
+        }
+
+        let length = self.version.code_length();
+        while txt.len() < length {
+            txt.push('1');
+        }
+        txt.truncate(length);
+
+        txt
+    }
+
+    /// Like [`Caverphone::encode`], but reports inputs without any letters to encode instead of
+    /// returning a code made only of padding.
+    pub fn try_encode(&self, name: &str) -> Result<String, PhoneticError> {
+        validate_encodable(name)?;
+
+        Ok(self.encode(name))
+    }
+
+    pub fn compare(&self, name1: &str, name2: &str) -> bool {
+        self.encode(name1) == self.encode(name2)
+    }
+}
+
+impl Default for Caverphone {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PhoneticEncoder for Caverphone {
+    fn encode(&self, val: &str) -> Result<String, PhoneticError> {
+        self.try_encode(val)
+    }
+
+    fn name(&self) -> &'static str {
+        match self.version {
+            CaverphoneVersion::One => "caverphone1",
+            CaverphoneVersion::Two => "caverphone2",
+        }
+    }
+
+    fn max_code_length(&self) -> Option<usize> {
+        Some(self.version.code_length())
+    }
+}
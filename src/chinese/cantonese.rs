@@ -0,0 +1,71 @@
+/// Common Hong Kong spellings of Cantonese surnames with the pinyin of the surnames they
+/// stand for. Spellings shared by several surnames list each of them.
+const SURNAMES: &[(&str, &[&str])] = &[
+    ("au", &["ou"]),
+    ("auyeung", &["ouyang"]),
+    ("chan", &["chen"]),
+    ("cheng", &["zheng"]),
+    ("cheung", &["zhang"]),
+    ("chiu", &["zhao"]),
+    ("choi", &["cai"]),
+    ("chow", &["zhou"]),
+    ("chu", &["zhu"]),
+    ("chung", &["zhong"]),
+    ("fok", &["huo"]),
+    ("fong", &["fang"]),
+    ("fung", &["feng"]),
+    ("ho", &["he"]),
+    ("hui", &["xu"]),
+    ("hung", &["hong", "xiong"]),
+    ("ip", &["ye"]),
+    ("kam", &["jin"]),
+    ("ko", &["gao"]),
+    ("kong", &["jiang"]),
+    ("koo", &["gu"]),
+    ("kwan", &["guan"]),
+    ("kwok", &["guo"]),
+    ("kwong", &["kuang"]),
+    ("lai", &["li", "lai"]),
+    ("lam", &["lin"]),
+    ("lau", &["liu"]),
+    ("law", &["luo"]),
+    ("lee", &["li"]),
+    ("leung", &["liang"]),
+    ("lo", &["lu", "luo"]),
+    ("lui", &["lei", "lv"]),
+    ("mak", &["mai"]),
+    ("mok", &["mo"]),
+    ("mui", &["mei"]),
+    ("ng", &["wu"]),
+    ("ngai", &["wei"]),
+    ("ngan", &["yan"]),
+    ("pang", &["peng"]),
+    ("poon", &["pan"]),
+    ("shek", &["shi"]),
+    ("shum", &["cen", "shen"]),
+    ("siu", &["xiao"]),
+    ("so", &["su"]),
+    ("szeto", &["situ"]),
+    ("tam", &["tan"]),
+    ("tang", &["deng"]),
+    ("ting", &["ding"]),
+    ("to", &["du"]),
+    ("tong", &["tang"]),
+    ("tsang", &["zeng"]),
+    ("tse", &["xie"]),
+    ("tsoi", &["cai"]),
+    ("tsui", &["xu", "cui"]),
+    ("wong", &["huang", "wang"]),
+    ("yeung", &["yang"]),
+    ("yip", &["ye"]),
+    ("yiu", &["yao"]),
+    ("yuen", &["yuan", "ruan"]),
+];
+
+/// Reads `piece` as a Cantonese surname spelling.
+pub(super) fn readings(piece: &str) -> Vec<String> {
+    SURNAMES
+        .binary_search_by(|(spelling, _)| spelling.cmp(&piece))
+        .map(|index| SURNAMES[index].1.iter().map(|s| s.to_string()).collect())
+        .unwrap_or_default()
+}
@@ -0,0 +1,413 @@
+/// Pinyin of common Chinese surnames, in simplified and traditional characters, including
+/// compound surnames of two characters. Sorted for binary search.
+const SURNAMES: &[(&str, &str)] = &[
+    ("丁", "ding"),
+    ("万", "wan"),
+    ("上官", "shangguan"),
+    ("东方", "dongfang"),
+    ("严", "yan"),
+    ("乔", "qiao"),
+    ("于", "yu"),
+    ("付", "fu"),
+    ("令狐", "linghu"),
+    ("任", "ren"),
+    ("伍", "wu"),
+    ("何", "he"),
+    ("余", "yu"),
+    ("侯", "hou"),
+    ("俞", "yu"),
+    ("倪", "ni"),
+    ("傅", "fu"),
+    ("公孙", "gongsun"),
+    ("公孫", "gongsun"),
+    ("关", "guan"),
+    ("冯", "feng"),
+    ("冼", "xian"),
+    ("凌", "ling"),
+    ("刁", "diao"),
+    ("刘", "liu"),
+    ("劉", "liu"),
+    ("包", "bao"),
+    ("区", "ou"),
+    ("區", "ou"),
+    ("卓", "zhuo"),
+    ("单", "shan"),
+    ("南宫", "nangong"),
+    ("南宮", "nangong"),
+    ("卢", "lu"),
+    ("史", "shi"),
+    ("叶", "ye"),
+    ("司徒", "situ"),
+    ("司馬", "sima"),
+    ("司马", "sima"),
+    ("向", "xiang"),
+    ("吕", "lv"),
+    ("吳", "wu"),
+    ("吴", "wu"),
+    ("呂", "lv"),
+    ("周", "zhou"),
+    ("唐", "tang"),
+    ("喬", "qiao"),
+    ("單", "shan"),
+    ("嚴", "yan"),
+    ("夏", "xia"),
+    ("夏侯", "xiahou"),
+    ("姚", "yao"),
+    ("姜", "jiang"),
+    ("孔", "kong"),
+    ("孙", "sun"),
+    ("孟", "meng"),
+    ("孫", "sun"),
+    ("宁", "ning"),
+    ("宇文", "yuwen"),
+    ("安", "an"),
+    ("宋", "song"),
+    ("容", "rong"),
+    ("寧", "ning"),
+    ("尉迟", "yuchi"),
+    ("尉遲", "yuchi"),
+    ("尤", "you"),
+    ("尹", "yin"),
+    ("岑", "cen"),
+    ("岳", "yue"),
+    ("崔", "cui"),
+    ("左", "zuo"),
+    ("巫", "wu"),
+    ("常", "chang"),
+    ("庄", "zhuang"),
+    ("康", "kang"),
+    ("廖", "liao"),
+    ("张", "zhang"),
+    ("張", "zhang"),
+    ("彭", "peng"),
+    ("徐", "xu"),
+    ("慕容", "murong"),
+    ("戴", "dai"),
+    ("文", "wen"),
+    ("方", "fang"),
+    ("施", "shi"),
+    ("易", "yi"),
+    ("曹", "cao"),
+    ("曾", "zeng"),
+    ("朱", "zhu"),
+    ("李", "li"),
+    ("杜", "du"),
+    ("杨", "yang"),
+    ("東方", "dongfang"),
+    ("林", "lin"),
+    ("柯", "ke"),
+    ("柳", "liu"),
+    ("梁", "liang"),
+    ("梅", "mei"),
+    ("楊", "yang"),
+    ("欧", "ou"),
+    ("欧阳", "ouyang"),
+    ("歐", "ou"),
+    ("歐陽", "ouyang"),
+    ("武", "wu"),
+    ("段", "duan"),
+    ("殷", "yin"),
+    ("毛", "mao"),
+    ("江", "jiang"),
+    ("汤", "tang"),
+    ("汪", "wang"),
+    ("沈", "shen"),
+    ("洪", "hong"),
+    ("涂", "tu"),
+    ("温", "wen"),
+    ("游", "you"),
+    ("湯", "tang"),
+    ("溫", "wen"),
+    ("滕", "teng"),
+    ("潘", "pan"),
+    ("澹台", "tantai"),
+    ("熊", "xiong"),
+    ("牛", "niu"),
+    ("王", "wang"),
+    ("甘", "gan"),
+    ("田", "tian"),
+    ("白", "bai"),
+    ("皇甫", "huangfu"),
+    ("盧", "lu"),
+    ("石", "shi"),
+    ("祝", "zhu"),
+    ("秦", "qin"),
+    ("程", "cheng"),
+    ("章", "zhang"),
+    ("端木", "duanmu"),
+    ("符", "fu"),
+    ("罗", "luo"),
+    ("羅", "luo"),
+    ("翁", "weng"),
+    ("聂", "nie"),
+    ("聶", "nie"),
+    ("肖", "xiao"),
+    ("胡", "hu"),
+    ("艾", "ai"),
+    ("苏", "su"),
+    ("范", "fan"),
+    ("莊", "zhuang"),
+    ("莫", "mo"),
+    ("萧", "xiao"),
+    ("萬", "wan"),
+    ("葉", "ye"),
+    ("葛", "ge"),
+    ("董", "dong"),
+    ("蒋", "jiang"),
+    ("蒲", "pu"),
+    ("蓝", "lan"),
+    ("蔡", "cai"),
+    ("蔣", "jiang"),
+    ("蕭", "xiao"),
+    ("薛", "xue"),
+    ("藍", "lan"),
+    ("蘇", "su"),
+    ("袁", "yuan"),
+    ("裴", "pei"),
+    ("西門", "ximen"),
+    ("西门", "ximen"),
+    ("覃", "qin"),
+    ("許", "xu"),
+    ("諸葛", "zhuge"),
+    ("謝", "xie"),
+    ("譚", "tan"),
+    ("许", "xu"),
+    ("诸葛", "zhuge"),
+    ("谢", "xie"),
+    ("谭", "tan"),
+    ("賀", "he"),
+    ("賈", "jia"),
+    ("賴", "lai"),
+    ("贺", "he"),
+    ("贾", "jia"),
+    ("赖", "lai"),
+    ("赵", "zhao"),
+    ("趙", "zhao"),
+    ("車", "che"),
+    ("车", "che"),
+    ("辛", "xin"),
+    ("邓", "deng"),
+    ("邝", "kuang"),
+    ("邢", "xing"),
+    ("邱", "qiu"),
+    ("邵", "shao"),
+    ("邹", "zou"),
+    ("郁", "yu"),
+    ("郑", "zheng"),
+    ("郝", "hao"),
+    ("郭", "guo"),
+    ("鄒", "zou"),
+    ("鄢", "yan"),
+    ("鄧", "deng"),
+    ("鄭", "zheng"),
+    ("鄺", "kuang"),
+    ("金", "jin"),
+    ("錢", "qian"),
+    ("鍾", "zhong"),
+    ("钟", "zhong"),
+    ("钱", "qian"),
+    ("長孫", "zhangsun"),
+    ("长孙", "zhangsun"),
+    ("閆", "yan"),
+    ("閔", "min"),
+    ("關", "guan"),
+    ("闫", "yan"),
+    ("闵", "min"),
+    ("阮", "ruan"),
+    ("陆", "lu"),
+    ("陈", "chen"),
+    ("陳", "chen"),
+    ("陶", "tao"),
+    ("陸", "lu"),
+    ("雷", "lei"),
+    ("霍", "huo"),
+    ("韋", "wei"),
+    ("韓", "han"),
+    ("韦", "wei"),
+    ("韩", "han"),
+    ("顧", "gu"),
+    ("顾", "gu"),
+    ("饒", "rao"),
+    ("饶", "rao"),
+    ("馬", "ma"),
+    ("馮", "feng"),
+    ("駱", "luo"),
+    ("马", "ma"),
+    ("骆", "luo"),
+    ("高", "gao"),
+    ("魏", "wei"),
+    ("魯", "lu"),
+    ("鲁", "lu"),
+    ("麥", "mai"),
+    ("麦", "mai"),
+    ("黃", "huang"),
+    ("黄", "huang"),
+    ("黎", "li"),
+    ("齊", "qi"),
+    ("齐", "qi"),
+    ("龍", "long"),
+    ("龔", "gong"),
+    ("龙", "long"),
+    ("龚", "gong"),
+];
+
+/// Pinyin of characters common in given names that are not also surnames. Sorted for binary
+/// search.
+const GIVEN_NAMES: &[(&str, &str)] = &[
+    ("一", "yi"),
+    ("东", "dong"),
+    ("中", "zhong"),
+    ("丹", "dan"),
+    ("丽", "li"),
+    ("义", "yi"),
+    ("云", "yun"),
+    ("亮", "liang"),
+    ("仁", "ren"),
+    ("伟", "wei"),
+    ("伦", "lun"),
+    ("佳", "jia"),
+    ("俊", "jun"),
+    ("信", "xin"),
+    ("倩", "qian"),
+    ("倫", "lun"),
+    ("偉", "wei"),
+    ("健", "jian"),
+    ("傑", "jie"),
+    ("光", "guang"),
+    ("兰", "lan"),
+    ("军", "jun"),
+    ("凤", "feng"),
+    ("刚", "gang"),
+    ("剛", "gang"),
+    ("勇", "yong"),
+    ("华", "hua"),
+    ("博", "bo"),
+    ("嘉", "jia"),
+    ("国", "guo"),
+    ("國", "guo"),
+    ("大", "da"),
+    ("天", "tian"),
+    ("娜", "na"),
+    ("娟", "juan"),
+    ("婉", "wan"),
+    ("婷", "ting"),
+    ("子", "zi"),
+    ("宇", "yu"),
+    ("宏", "hong"),
+    ("家", "jia"),
+    ("小", "xiao"),
+    ("山", "shan"),
+    ("峰", "feng"),
+    ("平", "ping"),
+    ("建", "jian"),
+    ("強", "qiang"),
+    ("强", "qiang"),
+    ("彬", "bin"),
+    ("德", "de"),
+    ("志", "zhi"),
+    ("思", "si"),
+    ("怡", "yi"),
+    ("惠", "hui"),
+    ("慧", "hui"),
+    ("成", "cheng"),
+    ("敏", "min"),
+    ("斌", "bin"),
+    ("新", "xin"),
+    ("明", "ming"),
+    ("春", "chun"),
+    ("晓", "xiao"),
+    ("晨", "chen"),
+    ("晶", "jing"),
+    ("智", "zhi"),
+    ("曉", "xiao"),
+    ("杰", "jie"),
+    ("東", "dong"),
+    ("桂", "gui"),
+    ("榮", "rong"),
+    ("欣", "xin"),
+    ("永", "yong"),
+    ("波", "bo"),
+    ("洁", "jie"),
+    ("洋", "yang"),
+    ("浩", "hao"),
+    ("海", "hai"),
+    ("涛", "tao"),
+    ("涵", "han"),
+    ("淑", "shu"),
+    ("潔", "jie"),
+    ("濤", "tao"),
+    ("燕", "yan"),
+    ("玉", "yu"),
+    ("玲", "ling"),
+    ("珍", "zhen"),
+    ("珠", "zhu"),
+    ("琳", "lin"),
+    ("琴", "qin"),
+    ("瑞", "rui"),
+    ("瑤", "yao"),
+    ("瑶", "yao"),
+    ("生", "sheng"),
+    ("睿", "rui"),
+    ("磊", "lei"),
+    ("礼", "li"),
+    ("祥", "xiang"),
+    ("福", "fu"),
+    ("禮", "li"),
+    ("秀", "xiu"),
+    ("穎", "ying"),
+    ("立", "li"),
+    ("紅", "hong"),
+    ("红", "hong"),
+    ("美", "mei"),
+    ("義", "yi"),
+    ("艳", "yan"),
+    ("艷", "yan"),
+    ("芬", "fen"),
+    ("芳", "fang"),
+    ("英", "ying"),
+    ("荣", "rong"),
+    ("莉", "li"),
+    ("華", "hua"),
+    ("萍", "ping"),
+    ("蘭", "lan"),
+    ("豪", "hao"),
+    ("貴", "gui"),
+    ("贵", "gui"),
+    ("超", "chao"),
+    ("軍", "jun"),
+    ("軒", "xuan"),
+    ("輝", "hui"),
+    ("轩", "xuan"),
+    ("辉", "hui"),
+    ("鑫", "xin"),
+    ("雪", "xue"),
+    ("雲", "yun"),
+    ("霞", "xia"),
+    ("静", "jing"),
+    ("靜", "jing"),
+    ("颖", "ying"),
+    ("飛", "fei"),
+    ("飞", "fei"),
+    ("鳳", "feng"),
+    ("鵬", "peng"),
+    ("鹏", "peng"),
+    ("麗", "li"),
+];
+
+/// Reads the surname or given-name character at the start of `chars`, preferring compound
+/// surnames, and returns its pinyin with the number of characters read.
+pub(super) fn reading(chars: &[char]) -> Option<(&'static str, usize)> {
+    let find = |table: &[(&'static str, &'static str)], han: &str| {
+        table
+            .binary_search_by(|(entry, _)| (*entry).cmp(han))
+            .ok()
+            .map(|index| table[index].1)
+    };
+
+    (1..=chars.len().min(2)).rev().find_map(|length| {
+        let han: String = chars[..length].iter().collect();
+        find(SURNAMES, &han)
+            .or_else(|| (length == 1).then(|| find(GIVEN_NAMES, &han)).flatten())
+            .map(|pinyin| (pinyin, length))
+    })
+}
@@ -0,0 +1,302 @@
+mod cantonese;
+#[cfg(feature = "han")]
+mod han;
+mod syllables;
+mod wade_giles;
+
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
+
+use crate::encoder::PhoneticEncoder;
+use crate::error::{PhoneticError, is_latin};
+
+/// Longest romanized syllable read as one piece, as in "ch'uang" or "auyeung".
+const MAX_PIECE: usize = 8;
+
+/// Upper bound on the keys produced for one value; each ambiguous syllable multiplies them,
+/// and a value with more is an error rather than a partial set that could miss a match.
+const MAX_KEYS: usize = 64;
+
+const DIAERESIS: char = '\u{0308}';
+
+/// Romanization systems read by [`ChinesePhonetic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChineseRomanization {
+    /// Hanyu Pinyin, with or without tone marks; "ü" may be written "v".
+    Pinyin,
+    /// Wade–Giles, with or without the apostrophes marking aspiration.
+    WadeGiles,
+    /// Hong Kong spellings of Cantonese surnames, such as "Cheung" or "Wong".
+    Cantonese,
+}
+
+impl ChineseRomanization {
+    fn name(self) -> &'static str {
+        match self {
+            ChineseRomanization::Pinyin => "pinyin",
+            ChineseRomanization::WadeGiles => "wade_giles",
+            ChineseRomanization::Cantonese => "cantonese",
+        }
+    }
+
+    fn readings(self, piece: &str) -> Vec<String> {
+        match self {
+            ChineseRomanization::Pinyin => syllables::readings(piece),
+            ChineseRomanization::WadeGiles => wade_giles::readings(piece),
+            ChineseRomanization::Cantonese => cantonese::readings(piece),
+        }
+    }
+}
+
+/// Canonical keys for Chinese names, so that the romanizations of one surname in different
+/// systems match: "Zhang", "Chang" and "Cheung" all have the key "ZHANG".
+///
+/// Each syllable is read in every enabled romanization and spelled as toneless pinyin, with
+/// "ü" written "V". A spelling can stand for several syllables ("Wong" for both "Huang" and
+/// "Wang"), so a value has one key per reading. Syllables may be joined, hyphenated or
+/// separated by spaces, hyphens or apostrophes, and a given name is read like the surname, so
+/// "Zhang Wei" and "Zhang-Wei" share the key "ZHANGWEI". With the `han` feature, common
+/// surnames and given-name characters written in Han characters are read too, so "張伟" has
+/// that key as well.
+pub struct ChinesePhonetic {
+    romanizations: Vec<ChineseRomanization>,
+}
+
+impl ChinesePhonetic {
+    /// Reads pinyin, Wade–Giles and Cantonese spellings.
+    pub fn new() -> Self {
+        ChinesePhonetic {
+            romanizations: vec![
+                ChineseRomanization::Pinyin,
+                ChineseRomanization::WadeGiles,
+                ChineseRomanization::Cantonese,
+            ],
+        }
+    }
+
+    /// Replaces the romanizations read.
+    pub fn with_romanizations<I>(mut self, romanizations: I) -> Self
+    where
+        I: IntoIterator<Item = ChineseRomanization>,
+    {
+        self.romanizations.clear();
+        for romanization in romanizations {
+            if !self.romanizations.contains(&romanization) {
+                self.romanizations.push(romanization);
+            }
+        }
+        self
+    }
+
+    /// Returns the key of every reading of `val`, sorted and without duplicates.
+    pub fn encode(&self, val: &str) -> Result<Vec<String>, PhoneticError> {
+        let chars: Vec<char> = val.chars().collect();
+        if chars.is_empty() {
+            return Err(PhoneticError::Empty);
+        }
+
+        let mut syllables: Vec<Vec<String>> = Vec::new();
+        let mut chunk = String::new();
+        let mut position = 0;
+
+        while position < chars.len() {
+            let c = chars[position];
+            match c {
+                '-' => self.push_chunk(&mut chunk, &mut syllables),
+                _ if c.is_whitespace() => self.push_chunk(&mut chunk, &mut syllables),
+                '\'' | '’' | '‘' | 'ʻ' => {
+                    // After a stop or affricate the apostrophe is Wade–Giles aspiration, as in
+                    // "Ch'en"; anywhere else it separates syllables, as in "Xi'an".
+                    if ["p", "t", "k", "ch", "ts", "tz"]
+                        .iter()
+                        .any(|initial| chunk.ends_with(initial))
+                    {
+                        chunk.push('\'');
+                    } else {
+                        self.push_chunk(&mut chunk, &mut syllables);
+                    }
+                }
+                _ if is_han(c) => {
+                    let Some((reading, length)) = han_reading(&chars[position..]) else {
+                        return Err(PhoneticError::UnsupportedScript { position, char: c });
+                    };
+                    self.push_chunk(&mut chunk, &mut syllables);
+                    syllables.push(vec![reading.to_string()]);
+                    position += length;
+                    continue;
+                }
+                _ if (is_latin(c) && c.is_alphabetic())
+                    || ('\u{0300}'..='\u{036F}').contains(&c) =>
+                {
+                    for c in c.nfd() {
+                        match c {
+                            DIAERESIS if chunk.ends_with('u') => {
+                                chunk.pop();
+                                chunk.push('ü');
+                            }
+                            _ if is_combining_mark(c) => {}
+                            _ => chunk.extend(c.to_lowercase()),
+                        }
+                    }
+                }
+                _ if c.is_alphabetic() => {
+                    return Err(PhoneticError::UnsupportedScript { position, char: c });
+                }
+                _ => return Err(PhoneticError::NonAlphabetic { position, char: c }),
+            }
+            position += 1;
+        }
+        self.push_chunk(&mut chunk, &mut syllables);
+
+        if syllables.is_empty() {
+            return Err(PhoneticError::NonAlphabetic {
+                position: 0,
+                char: chars[0],
+            });
+        }
+
+        let mut keys = vec![String::new()];
+        for readings in &syllables {
+            if keys.len() * readings.len() > MAX_KEYS {
+                return Err(PhoneticError::TooManyReadings { limit: MAX_KEYS });
+            }
+            keys = keys
+                .iter()
+                .flat_map(|key| {
+                    readings
+                        .iter()
+                        .map(move |reading| format!("{key}{reading}"))
+                })
+                .collect();
+        }
+        let mut keys: Vec<String> = keys.iter().map(|key| key.to_uppercase()).collect();
+        keys.sort();
+        keys.dedup();
+
+        Ok(keys)
+    }
+
+    /// Two values match when any of their keys are equal.
+    pub fn compare(&self, val1: &str, val2: &str) -> Result<bool, PhoneticError> {
+        let keys1 = self.encode(val1)?;
+        let keys2 = self.encode(val2)?;
+
+        Ok(keys1.iter().any(|key| keys2.contains(key)))
+    }
+
+    /// Pinyin syllables that `piece` may stand for in any enabled romanization.
+    fn readings(&self, piece: &str) -> Vec<String> {
+        let mut readings: Vec<String> = self
+            .romanizations
+            .iter()
+            .flat_map(|romanization| romanization.readings(piece))
+            .collect();
+        readings.sort();
+        readings.dedup();
+        readings
+    }
+
+    /// Splits a run of letters into the fewest syllables, preferring longer syllables first,
+    /// and appends the readings of each. A run that cannot be split is kept as it is.
+    fn push_chunk(&self, chunk: &mut String, syllables: &mut Vec<Vec<String>>) {
+        if chunk.is_empty() {
+            return;
+        }
+
+        let bounds: Vec<usize> = chunk
+            .char_indices()
+            .map(|(i, _)| i)
+            .chain([chunk.len()])
+            .collect();
+        let n = bounds.len() - 1;
+
+        // best[i] is the fewest syllables spelling the letters from i, with the first of them.
+        let mut best: Vec<Option<(usize, usize, Vec<String>)>> = vec![None; n + 1];
+        best[n] = Some((0, n, Vec::new()));
+        for i in (0..n).rev() {
+            for end in (i + 1..=n.min(i + MAX_PIECE)).rev() {
+                let Some((count, _, _)) = best[end] else {
+                    continue;
+                };
+                if best[i]
+                    .as_ref()
+                    .is_some_and(|(best_count, _, _)| *best_count <= count + 1)
+                {
+                    continue;
+                }
+                let readings = self.readings(&chunk[bounds[i]..bounds[end]]);
+                if !readings.is_empty() {
+                    best[i] = Some((count + 1, end, readings));
+                }
+            }
+        }
+
+        if best[0].is_some() {
+            let mut i = 0;
+            while i < n {
+                let Some((_, end, readings)) = best[i].take() else {
+                    break;
+                };
+                syllables.push(readings);
+                i = end;
+            }
+        } else {
+            syllables.push(vec![chunk.replace('\'', "").replace('ü', "v")]);
+        }
+        chunk.clear();
+    }
+}
+
+impl Default for ChinesePhonetic {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PhoneticEncoder for ChinesePhonetic {
+    /// Joins the keys with '|'.
+    fn encode(&self, val: &str) -> Result<String, PhoneticError> {
+        Ok(ChinesePhonetic::encode(self, val)?.join("|"))
+    }
+
+    fn compare(&self, val1: &str, val2: &str) -> Result<bool, PhoneticError> {
+        ChinesePhonetic::compare(self, val1, val2)
+    }
+
+    fn name(&self) -> &'static str {
+        "chinese"
+    }
+
+    fn max_code_length(&self) -> Option<usize> {
+        None
+    }
+
+    fn config(&self) -> String {
+        let romanizations: Vec<&str> = self
+            .romanizations
+            .iter()
+            .map(|romanization| romanization.name())
+            .collect();
+        format!("romanizations={}", romanizations.join("+"))
+    }
+}
+
+fn is_han(c: char) -> bool {
+    matches!(
+        c,
+        '\u{3400}'..='\u{4DBF}'
+            | '\u{4E00}'..='\u{9FFF}'
+            | '\u{F900}'..='\u{FAFF}'
+            | '\u{20000}'..='\u{2FFFF}'
+    )
+}
+
+#[cfg(feature = "han")]
+fn han_reading(chars: &[char]) -> Option<(&'static str, usize)> {
+    han::reading(chars)
+}
+
+#[cfg(not(feature = "han"))]
+fn han_reading(_chars: &[char]) -> Option<(&'static str, usize)> {
+    None
+}
@@ -0,0 +1,98 @@
+use super::syllables::is_syllable;
+
+/// Wade–Giles initials, longest first, with the pinyin initials each may stand for. The
+/// apostrophe marking aspiration is often left out, so a bare "p" reads as "b" or "p".
+const INITIALS: &[(&str, &[&str])] = &[
+    ("ch'", &["ch"]),
+    ("ts'", &["c"]),
+    ("tz'", &["c"]),
+    ("ch", &["zh", "ch"]),
+    ("hs", &["x"]),
+    ("sh", &["sh"]),
+    ("ts", &["z", "c"]),
+    ("tz", &["z", "c"]),
+    ("ss", &["s"]),
+    ("sz", &["s"]),
+    ("p'", &["p"]),
+    ("t'", &["t"]),
+    ("k'", &["k"]),
+    ("p", &["b", "p"]),
+    ("t", &["d", "t"]),
+    ("k", &["g", "k"]),
+    ("m", &["m"]),
+    ("f", &["f"]),
+    ("n", &["n"]),
+    ("l", &["l"]),
+    ("h", &["h"]),
+    ("j", &["r"]),
+    ("s", &["s"]),
+    ("w", &["w"]),
+    ("y", &["y"]),
+    ("", &[""]),
+];
+
+/// Reads `piece` as a Wade–Giles syllable, returning every pinyin syllable it may stand for.
+/// The umlaut of "ü" is often left out too, so a "u" is also tried as "ü".
+pub(super) fn readings(piece: &str) -> Vec<String> {
+    let mut readings = Vec::new();
+
+    for (initial, pinyin_initials) in INITIALS {
+        let Some(wg_final) = piece.strip_prefix(initial) else {
+            continue;
+        };
+
+        let mut finals = vec![wg_final.to_string()];
+        if !wg_final.contains('ü') && wg_final.contains('u') {
+            finals.push(wg_final.replacen('u', "ü", 1));
+        }
+
+        for wg_final in &finals {
+            // "ch" and "hs" before "i" or "ü" are the palatals "j", "q" and "x".
+            let palatal =
+                wg_final.starts_with('ü') || (wg_final.starts_with('i') && wg_final != "ih");
+            let pinyin_initials: &[&str] = match *initial {
+                "ch" if palatal => &["j", "q"],
+                "ch'" if palatal => &["q"],
+                "hs" if !palatal => continue,
+                _ => pinyin_initials,
+            };
+            let pinyin_final = convert_final(initial.trim_end_matches('\''), wg_final);
+
+            for pinyin_initial in pinyin_initials {
+                let syllable = match pinyin_initial {
+                    &("j" | "q" | "x" | "y") => {
+                        format!("{pinyin_initial}{}", pinyin_final.replace('ü', "u"))
+                    }
+                    _ => format!("{pinyin_initial}{}", pinyin_final.replace('ü', "v")),
+                };
+                if is_syllable(&syllable) && !readings.contains(&syllable) {
+                    readings.push(syllable);
+                }
+            }
+        }
+    }
+
+    readings
+}
+
+/// Spells a Wade–Giles final the pinyin way; which spelling applies can depend on the initial.
+fn convert_final<'a>(initial: &str, wg_final: &'a str) -> &'a str {
+    match (initial, wg_final) {
+        (_, "ih") => "i",
+        ("tz" | "ss" | "sz", "u") => "i",
+        (_, "erh") => "er",
+        ("k" | "h" | "", "o") => "e",
+        ("p" | "m" | "f" | "w" | "y", "o") => "o",
+        (_, "o") => "uo",
+        ("y", "u") => "ou",
+        ("y", "en") => "an",
+        (_, "eh") => "e",
+        (_, "ieh") => "ie",
+        (_, "ueh" | "üeh") => "üe",
+        (_, "ien") => "ian",
+        (_, "iung") => "iong",
+        (_, "ung") => "ong",
+        (_, "uei") => "ui",
+        _ => wg_final,
+    }
+}
@@ -0,0 +1,118 @@
+use crate::encoder::PhoneticEncoder;
+use crate::error::{PhoneticError, validate_alphabetic};
+use crate::transliterate::ascii_letters;
+
+pub struct ColognePhonetic;
+
+impl ColognePhonetic {
+    pub fn new() -> Self {
+        ColognePhonetic
+    }
+
+    pub fn encode(&self, val: &str) -> Result<String, PhoneticError> {
+        validate_alphabetic(val)?;
+
+        let letters = ascii_letters(val)?;
+        let mut digits = String::with_capacity(letters.len());
+
+        for (i, &c) in letters.iter().enumerate() {
+            let prev = if i > 0 { letters[i - 1] } else { '\0' };
+            let next = letters.get(i + 1).copied().unwrap_or('\0');
+
+            let code = match c {
+                'A' | 'E' | 'I' | 'J' | 'O' | 'U' | 'Y' => "0",
+                'H' => "-",
+                'B' => "1",
+                'P' => {
+                    if next == 'H' {
+                        "3"
+                    } else {
+                        "1"
+                    }
+                }
+                'D' | 'T' => {
+                    if matches!(next, 'C' | 'S' | 'Z') {
+                        "8"
+                    } else {
+                        "2"
+                    }
+                }
+                'F' | 'V' | 'W' => "3",
+                'G' | 'K' | 'Q' => "4",
+                'C' => {
+                    if i == 0 {
+                        if matches!(next, 'A' | 'H' | 'K' | 'L' | 'O' | 'Q' | 'R' | 'U' | 'X') {
+                            "4"
+                        } else {
+                            "8"
+                        }
+                    } else if matches!(prev, 'S' | 'Z') {
+                        "8"
+                    } else if matches!(next, 'A' | 'H' | 'K' | 'O' | 'Q' | 'U' | 'X') {
+                        "4"
+                    } else {
+                        "8"
+                    }
+                }
+                'X' => {
+                    if matches!(prev, 'C' | 'K' | 'Q') {
+                        "8"
+                    } else {
+                        "48"
+                    }
+                }
+                'L' => "5",
+                'M' | 'N' => "6",
+                'R' => "7",
+                'S' | 'Z' => "8",
+                _ => "",
+            };
+            digits.push_str(code);
+        }
+
+        Ok(self.collapse(&digits))
+    }
+
+    pub fn compare(&self, val1: &str, val2: &str) -> Result<bool, PhoneticError> {
+        let code1 = self.encode(val1)?;
+        let code2 = self.encode(val2)?;
+
+        Ok(code1 == code2)
+    }
+
+    /// Removes repeated digits, then every "0" except a leading one. An "H" is written as "-",
+    /// which is never emitted but keeps the codes on either side of it from merging.
+    fn collapse(&self, digits: &str) -> String {
+        let mut result = String::with_capacity(digits.len());
+        let mut last = None;
+
+        for (i, c) in digits.chars().enumerate() {
+            if c != '-' && last != Some(c) && (c != '0' || i == 0) {
+                result.push(c);
+            }
+            last = Some(c);
+        }
+
+        result
+    }
+}
+
+impl Default for ColognePhonetic {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PhoneticEncoder for ColognePhonetic {
+    fn encode(&self, val: &str) -> Result<String, PhoneticError> {
+        ColognePhonetic::encode(self, val)
+    }
+
+    fn name(&self) -> &'static str {
+        "cologne_phonetic"
+    }
+
+    fn max_code_length(&self) -> Option<usize> {
+        None
+    }
+}
@@ -0,0 +1,299 @@
+use crate::encoder::PhoneticEncoder;
+use crate::error::{PhoneticError, validate_alphabetic};
+use crate::transliterate::fold_latin;
+
+const CODE_LENGTH: usize = 6;
+
+// Each rule is (pattern, code at the start of the name, code before a vowel, code otherwise).
+// Alternatives are separated by '|' and an empty code means "not coded".
+const RULES: &[(&str, &str, &str, &str)] = &[
+    ("AI", "0", "1", ""),
+    ("AJ", "0", "1", ""),
+    ("AY", "0", "1", ""),
+    ("AU", "0", "7", ""),
+    ("A", "0", "", ""),
+    ("B", "7", "7", "7"),
+    ("CHS", "5", "54", "54"),
+    ("CH", "5|4", "5|4", "5|4"),
+    ("CK", "5|45", "5|45", "5|45"),
+    ("CZ", "4", "4", "4"),
+    ("CS", "4", "4", "4"),
+    ("CSZ", "4", "4", "4"),
+    ("CZS", "4", "4", "4"),
+    ("C", "5|4", "5|4", "5|4"),
+    ("DRZ", "4", "4", "4"),
+    ("DRS", "4", "4", "4"),
+    ("DS", "4", "4", "4"),
+    ("DSH", "4", "4", "4"),
+    ("DSZ", "4", "4", "4"),
+    ("DZ", "4", "4", "4"),
+    ("DZH", "4", "4", "4"),
+    ("DZS", "4", "4", "4"),
+    ("D", "3", "3", "3"),
+    ("DT", "3", "3", "3"),
+    ("EI", "0", "1", ""),
+    ("EJ", "0", "1", ""),
+    ("EY", "0", "1", ""),
+    ("EU", "1", "1", ""),
+    ("E", "0", "", ""),
+    ("FB", "7", "7", "7"),
+    ("F", "7", "7", "7"),
+    ("G", "5", "5", "5"),
+    ("H", "5", "5", ""),
+    ("IA", "1", "", ""),
+    ("IE", "1", "", ""),
+    ("IO", "1", "", ""),
+    ("IU", "1", "", ""),
+    ("I", "0", "", ""),
+    ("J", "1|4", "|4", "|4"),
+    ("KS", "5", "54", "54"),
+    ("KH", "5", "5", "5"),
+    ("K", "5", "5", "5"),
+    ("L", "8", "8", "8"),
+    ("MN", "66", "66", "66"),
+    ("M", "6", "6", "6"),
+    ("NM", "66", "66", "66"),
+    ("N", "6", "6", "6"),
+    ("OI", "0", "1", ""),
+    ("OJ", "0", "1", ""),
+    ("OY", "0", "1", ""),
+    ("O", "0", "", ""),
+    ("P", "7", "7", "7"),
+    ("PF", "7", "7", "7"),
+    ("PH", "7", "7", "7"),
+    ("Q", "5", "5", "5"),
+    ("RZ", "94|4", "94|4", "94|4"),
+    ("RS", "94|4", "94|4", "94|4"),
+    ("R", "9", "9", "9"),
+    ("SCHTSCH", "2", "4", "4"),
+    ("SCHTSH", "2", "4", "4"),
+    ("SCHTCH", "2", "4", "4"),
+    ("SCH", "4", "4", "4"),
+    ("SHTCH", "2", "4", "4"),
+    ("SHCH", "2", "4", "4"),
+    ("SHTSH", "2", "4", "4"),
+    ("SHT", "2", "43", "43"),
+    ("SCHT", "2", "43", "43"),
+    ("SCHD", "2", "43", "43"),
+    ("SH", "4", "4", "4"),
+    ("STCH", "2", "4", "4"),
+    ("STSCH", "2", "4", "4"),
+    ("SC", "2", "4", "4"),
+    ("STRZ", "2", "4", "4"),
+    ("STRS", "2", "4", "4"),
+    ("STSH", "2", "4", "4"),
+    ("ST", "2", "43", "43"),
+    ("SZCZ", "2", "4", "4"),
+    ("SZCS", "2", "4", "4"),
+    ("SZT", "2", "43", "43"),
+    ("SHD", "2", "43", "43"),
+    ("SZD", "2", "43", "43"),
+    ("SD", "2", "43", "43"),
+    ("SZ", "4", "4", "4"),
+    ("S", "4", "4", "4"),
+    ("TCH", "4", "4", "4"),
+    ("TTCH", "4", "4", "4"),
+    ("TTSCH", "4", "4", "4"),
+    ("TH", "3", "3", "3"),
+    ("TRZ", "4", "4", "4"),
+    ("TRS", "4", "4", "4"),
+    ("TSCH", "4", "4", "4"),
+    ("TSH", "4", "4", "4"),
+    ("TS", "4", "4", "4"),
+    ("TTS", "4", "4", "4"),
+    ("TTSZ", "4", "4", "4"),
+    ("TC", "4", "4", "4"),
+    ("TZ", "4", "4", "4"),
+    ("TTZ", "4", "4", "4"),
+    ("TZS", "4", "4", "4"),
+    ("TSZ", "4", "4", "4"),
+    ("T", "3", "3", "3"),
+    ("UI", "0", "1", ""),
+    ("UJ", "0", "1", ""),
+    ("UY", "0", "1", ""),
+    ("UE", "0", "", ""),
+    ("U", "0", "", ""),
+    ("V", "7", "7", "7"),
+    ("W", "7", "7", "7"),
+    ("X", "5", "54", "54"),
+    ("Y", "1", "", ""),
+    ("ZDZ", "2", "4", "4"),
+    ("ZDZH", "2", "4", "4"),
+    ("ZHDZH", "2", "4", "4"),
+    ("ZD", "2", "43", "43"),
+    ("ZHD", "2", "43", "43"),
+    ("ZH", "4", "4", "4"),
+    ("ZS", "4", "4", "4"),
+    ("ZSCH", "4", "4", "4"),
+    ("ZSH", "4", "4", "4"),
+    ("Z", "4", "4", "4"),
+];
+
+struct Rule {
+    pattern: Vec<char>,
+    at_start: Vec<&'static str>,
+    before_vowel: Vec<&'static str>,
+    other: Vec<&'static str>,
+}
+
+#[derive(Clone)]
+struct Branch {
+    code: String,
+    last_replacement: Option<&'static str>,
+}
+
+// Branches with the same code are merged whatever their last replacement, as in the
+// reference implementation.
+impl PartialEq for Branch {
+    fn eq(&self, other: &Self) -> bool {
+        self.code == other.code
+    }
+}
+
+pub struct DaitchMokotoff {
+    rules: Vec<Rule>,
+}
+
+impl DaitchMokotoff {
+    pub fn new() -> Self {
+        let mut rules: Vec<Rule> = RULES
+            .iter()
+            .map(|(pattern, at_start, before_vowel, other)| Rule {
+                pattern: pattern.chars().collect(),
+                at_start: at_start.split('|').collect(),
+                before_vowel: before_vowel.split('|').collect(),
+                other: other.split('|').collect(),
+            })
+            .collect();
+
+        // Longer patterns take precedence, e.g. "SCH" over "SH" over "S".
+        rules.sort_by_key(|rule| std::cmp::Reverse(rule.pattern.len()));
+
+        DaitchMokotoff { rules }
+    }
+
+    /// Returns every code produced by the branches of ambiguous letter combinations, sorted and
+    /// without duplicates.
+    pub fn encode(&self, val: &str) -> Result<Vec<String>, PhoneticError> {
+        validate_alphabetic(val)?;
+
+        let chars: Vec<char> = fold_latin(val).to_uppercase().chars().collect();
+        let mut branches = vec![Branch {
+            code: String::with_capacity(CODE_LENGTH),
+            last_replacement: None,
+        }];
+        let mut last_char = None;
+        let mut index = 0;
+
+        while index < chars.len() {
+            let c = chars[index];
+            let Some(rule) = self.find_rule(&chars[index..]) else {
+                index += 1;
+                continue;
+            };
+
+            let replacements = if last_char.is_none() {
+                &rule.at_start
+            } else if chars
+                .get(index + rule.pattern.len())
+                .is_some_and(|&next| is_vowel(next))
+            {
+                &rule.before_vowel
+            } else {
+                &rule.other
+            };
+
+            // "MN" and "NM" spanning two rules are coded twice rather than collapsed.
+            let force = matches!((last_char, c), (Some('M'), 'N') | (Some('N'), 'M'));
+
+            let mut next_branches: Vec<Branch> = Vec::with_capacity(branches.len());
+            for branch in &branches {
+                for replacement in replacements {
+                    let mut next = branch.clone();
+                    next.process_replacement(replacement, force);
+                    if !next_branches.contains(&next) {
+                        next_branches.push(next);
+                    }
+                }
+            }
+            branches = next_branches;
+
+            last_char = Some(c);
+            index += rule.pattern.len();
+        }
+
+        let mut codes: Vec<String> = branches
+            .into_iter()
+            .map(|branch| {
+                let mut code = branch.code;
+                while code.len() < CODE_LENGTH {
+                    code.push('0');
+                }
+                code
+            })
+            .collect();
+        codes.sort();
+        codes.dedup();
+
+        Ok(codes)
+    }
+
+    /// Two values match when any of their branch codes are equal.
+    pub fn compare(&self, val1: &str, val2: &str) -> Result<bool, PhoneticError> {
+        let codes1 = self.encode(val1)?;
+        let codes2 = self.encode(val2)?;
+
+        Ok(codes1.iter().any(|code| codes2.contains(code)))
+    }
+
+    fn find_rule(&self, input: &[char]) -> Option<&Rule> {
+        self.rules
+            .iter()
+            .find(|rule| input.starts_with(&rule.pattern))
+    }
+}
+
+impl Branch {
+    fn process_replacement(&mut self, replacement: &'static str, force: bool) {
+        let append = match self.last_replacement {
+            None => true,
+            Some(last) => !last.ends_with(replacement) || force,
+        };
+
+        if append && self.code.len() < CODE_LENGTH {
+            self.code.push_str(replacement);
+            self.code.truncate(CODE_LENGTH);
+        }
+
+        self.last_replacement = Some(replacement);
+    }
+}
+
+impl Default for DaitchMokotoff {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PhoneticEncoder for DaitchMokotoff {
+    /// Joins the branch codes with '|'.
+    fn encode(&self, val: &str) -> Result<String, PhoneticError> {
+        Ok(DaitchMokotoff::encode(self, val)?.join("|"))
+    }
+
+    fn compare(&self, val1: &str, val2: &str) -> Result<bool, PhoneticError> {
+        DaitchMokotoff::compare(self, val1, val2)
+    }
+
+    fn name(&self) -> &'static str {
+        "daitch_mokotoff"
+    }
+
+    fn max_code_length(&self) -> Option<usize> {
+        Some(CODE_LENGTH)
+    }
+}
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'A' | 'E' | 'I' | 'O' | 'U')
+}
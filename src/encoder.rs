@@ -0,0 +1,78 @@
+use crate::error::PhoneticError;
+use crate::similarity::SimilarityMeasure;
+use crate::{
+    Caverphone, CaverphoneVersion, ChinesePhonetic, ColognePhonetic, DaitchMokotoff,
+    DoubleMetaphone, Eudex, IndicPhonetic, MatchRating, Metaphone, MultilingualPhonetic, Nysiis,
+    RefinedSoundex, Soundex,
+};
+
+pub trait PhoneticEncoder {
+    fn encode(&self, val: &str) -> Result<String, PhoneticError>;
+
+    fn compare(&self, val1: &str, val2: &str) -> Result<bool, PhoneticError> {
+        Ok(self.encode(val1)? == self.encode(val2)?)
+    }
+
+    /// Graded alternative to [`PhoneticEncoder::compare`]: the Jaro–Winkler similarity of the
+    /// two codes, in `[0, 1]`.
+    fn similarity(&self, val1: &str, val2: &str) -> Result<f64, PhoneticError> {
+        Ok(SimilarityMeasure::JaroWinkler.score(&self.encode(val1)?, &self.encode(val2)?))
+    }
+
+    fn name(&self) -> &'static str;
+
+    /// Maximum length of the produced code, or `None` if codes are unbounded.
+    fn max_code_length(&self) -> Option<usize>;
+
+    /// The settings that affect the codes produced, empty when there are none. Two encoders
+    /// with the same name and config produce the same codes.
+    fn config(&self) -> String {
+        String::new()
+    }
+}
+
+/// Formats an optional code length for [`PhoneticEncoder::config`].
+pub(crate) fn format_max_length(max_length: Option<usize>) -> String {
+    max_length.map_or_else(|| "none".to_string(), |length| length.to_string())
+}
+
+/// Names accepted by [`encoder_by_name`], matching [`PhoneticEncoder::name`].
+pub const ENCODER_NAMES: &[&str] = &[
+    "metaphone",
+    "double_metaphone",
+    "nysiis",
+    "soundex",
+    "refined_soundex",
+    "daitch_mokotoff",
+    "multilingual",
+    "cologne_phonetic",
+    "caverphone1",
+    "caverphone2",
+    "match_rating",
+    "eudex",
+    "indic",
+    "chinese",
+];
+
+/// Returns the encoder whose [`PhoneticEncoder::name`] is `name`, in its default configuration.
+pub fn encoder_by_name(name: &str) -> Option<Box<dyn PhoneticEncoder>> {
+    let encoder: Box<dyn PhoneticEncoder> = match name {
+        "metaphone" => Box::new(Metaphone::new()),
+        "double_metaphone" => Box::new(DoubleMetaphone::new()),
+        "nysiis" => Box::new(Nysiis::new()),
+        "soundex" => Box::new(Soundex::new()),
+        "refined_soundex" => Box::new(RefinedSoundex::new()),
+        "daitch_mokotoff" => Box::new(DaitchMokotoff::new()),
+        "multilingual" => Box::new(MultilingualPhonetic::new()),
+        "cologne_phonetic" => Box::new(ColognePhonetic::new()),
+        "caverphone1" => Box::new(Caverphone::with_version(CaverphoneVersion::One)),
+        "caverphone2" => Box::new(Caverphone::with_version(CaverphoneVersion::Two)),
+        "match_rating" => Box::new(MatchRating::new()),
+        "eudex" => Box::new(Eudex::new()),
+        "indic" => Box::new(IndicPhonetic::new()),
+        "chinese" => Box::new(ChinesePhonetic::new()),
+        _ => return None,
+    };
+
+    Some(encoder)
+}
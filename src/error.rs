@@ -0,0 +1,117 @@
+use std::error::Error;
+use std::fmt;
+
+use unicode_normalization::char::is_combining_mark;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PhoneticError {
+    Empty,
+    NonAlphabetic {
+        position: usize,
+        char: char,
+    },
+    UnsupportedScript {
+        position: usize,
+        char: char,
+    },
+    /// The value has more readings than an encoder producing several codes keeps, so some
+    /// would be missing from its codes.
+    TooManyReadings {
+        limit: usize,
+    },
+}
+
+impl fmt::Display for PhoneticError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PhoneticError::Empty => write!(f, "input is empty"),
+            PhoneticError::NonAlphabetic { position, char } => {
+                write!(
+                    f,
+                    "non-alphabetic character {:?} at position {}",
+                    char, position
+                )
+            }
+            PhoneticError::UnsupportedScript { position, char } => {
+                write!(
+                    f,
+                    "character {:?} at position {} is not in a supported script",
+                    char, position
+                )
+            }
+            PhoneticError::TooManyReadings { limit } => {
+                write!(f, "input has more than {} readings", limit)
+            }
+        }
+    }
+}
+
+impl Error for PhoneticError {}
+
+/// Checks that `val` is a non-empty run of Latin-script letters.
+///
+/// Positions in the returned error are character offsets, not byte offsets.
+pub(crate) fn validate_alphabetic(val: &str) -> Result<(), PhoneticError> {
+    validate_letters(val, |_, c| is_latin(c))
+}
+
+/// Checks that `val` is a non-empty run of letters, each accepted by `supported`, which is
+/// given its position too. Combining marks that `supported` accepts, such as Arabic vowel
+/// marks, count as letters, as does a hyphen it accepts, such as the one in "al-Rashid".
+pub(crate) fn validate_letters(
+    val: &str,
+    supported: impl Fn(usize, char) -> bool,
+) -> Result<(), PhoneticError> {
+    if val.is_empty() {
+        return Err(PhoneticError::Empty);
+    }
+
+    for (position, c) in val.chars().enumerate() {
+        let supported = supported(position, c);
+        let letter = c.is_alphabetic() || (supported && (is_combining_mark(c) || c == '-'));
+        if !letter {
+            return Err(PhoneticError::NonAlphabetic { position, char: c });
+        }
+        if !supported {
+            return Err(PhoneticError::UnsupportedScript { position, char: c });
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that `val` contains at least one ASCII letter, for encoders that silently drop
+/// everything else.
+pub(crate) fn validate_encodable(val: &str) -> Result<(), PhoneticError> {
+    if val.is_empty() {
+        return Err(PhoneticError::Empty);
+    }
+
+    if val.chars().any(|c| c.is_ascii_alphabetic()) {
+        return Ok(());
+    }
+
+    Err(
+        match val.chars().enumerate().find(|(_, c)| c.is_alphabetic()) {
+            Some((position, c)) => PhoneticError::UnsupportedScript { position, char: c },
+            None => PhoneticError::NonAlphabetic {
+                position: 0,
+                char: val.chars().next().unwrap(),
+            },
+        },
+    )
+}
+
+pub(crate) fn is_latin(c: char) -> bool {
+    c.is_ascii_alphabetic()
+        || matches!(c,
+            '\u{00AA}' | '\u{00BA}'
+            | '\u{00C0}'..='\u{024F}'
+            | '\u{1E00}'..='\u{1EFF}'
+            | '\u{2C60}'..='\u{2C7F}'
+            | '\u{A720}'..='\u{A7FF}'
+            | '\u{AB30}'..='\u{AB6F}'
+            | '\u{FB00}'..='\u{FB06}'
+            | '\u{FF21}'..='\u{FF3A}'
+            | '\u{FF41}'..='\u{FF5A}')
+}
@@ -0,0 +1,173 @@
+use crate::encoder::PhoneticEncoder;
+use crate::error::{PhoneticError, validate_encodable};
+use crate::transliterate::fold_latin;
+
+/// Phone of each letter after the first. Bits, from the highest, mark whether the phone is
+/// a vowel or semivowel, its place of articulation and its manner; similar phones differ in
+/// few bits, and a phone equal to the previous one but for the lowest bit is not repeated.
+const PHONES: [u8; 26] = [
+    0,          // a
+    0b01001000, // b
+    0b00001100, // c
+    0b00011000, // d
+    0,          // e
+    0b01000100, // f
+    0b00001000, // g
+    0b00000100, // h
+    1,          // i
+    0b00000101, // j
+    0b00001001, // k
+    0b10100000, // l
+    0b00000010, // m
+    0b00010010, // n
+    0,          // o
+    0b01001001, // p
+    0b10101000, // q
+    0b10100001, // r
+    0b00010100, // s
+    0b00011101, // t
+    1,          // u
+    0b01000101, // v
+    0b00000000, // w
+    0b10000100, // x
+    1,          // y
+    0b10010100, // z
+];
+
+/// Phone of the first letter, which also tells vowels apart.
+const INJECTIVE_PHONES: [u8; 26] = [
+    0b10000100, // a
+    0b00100100, // b
+    0b00000110, // c
+    0b00001100, // d
+    0b11011000, // e
+    0b00100010, // f
+    0b00000100, // g
+    0b00000010, // h
+    0b11111000, // i
+    0b00000011, // j
+    0b00000101, // k
+    0b01010000, // l
+    0b00000001, // m
+    0b00001001, // n
+    0b10010100, // o
+    0b00100101, // p
+    0b01010100, // q
+    0b01010001, // r
+    0b00001010, // s
+    0b00001110, // t
+    0b11100001, // u
+    0b00100011, // v
+    0b00000000, // w
+    0b01000010, // x
+    0b11100100, // y
+    0b01001010, // z
+];
+
+/// Names closer than this by [`Eudex::distance`] are considered a match.
+const MATCH_THRESHOLD: u32 = 10;
+
+/// Largest possible [`Eudex::distance`], between a hash and its complement.
+const MAX_DISTANCE: u32 = 255 * 8;
+
+/// Eudex, which hashes a name to a `u64` whose bitwise difference from another hash measures
+/// how different the names sound.
+///
+/// The first letter fills the top byte and the following distinct phones the lower bytes,
+/// from the most significant. Letters are folded to A–Z first, and other characters are
+/// ignored.
+pub struct Eudex;
+
+impl Eudex {
+    pub fn new() -> Self {
+        Eudex
+    }
+
+    pub fn hash(&self, val: &str) -> Result<u64, PhoneticError> {
+        let val = fold_latin(val);
+        validate_encodable(&val)?;
+
+        let mut chars = val.chars().map(|c| c.to_ascii_lowercase());
+        let first = match chars.next() {
+            Some(c @ 'a'..='z') => INJECTIVE_PHONES[c as usize - 'a' as usize],
+            _ => 0,
+        };
+
+        let mut hash: u64 = 0;
+        // Seven phones fit below the first letter's byte.
+        let mut phones = 0;
+        for c in chars {
+            if phones == 7 {
+                break;
+            }
+            if !c.is_ascii_lowercase() {
+                continue;
+            }
+
+            let phone = PHONES[c as usize - 'a' as usize];
+            if hash & 0xFE != u64::from(phone & 0xFE) {
+                hash = (hash << 8) | u64::from(phone);
+                phones += 1;
+            }
+        }
+
+        Ok(hash | (u64::from(first) << 56))
+    }
+
+    /// Encodes `val` as its hash in 16 hexadecimal digits.
+    pub fn encode(&self, val: &str) -> Result<String, PhoneticError> {
+        Ok(format!("{:016x}", self.hash(val)?))
+    }
+
+    /// Whether the hashes of `val1` and `val2` are within a small [`Eudex::distance`].
+    pub fn compare(&self, val1: &str, val2: &str) -> Result<bool, PhoneticError> {
+        Ok(Self::distance(self.hash(val1)?, self.hash(val2)?) < MATCH_THRESHOLD)
+    }
+
+    /// Counts the bits in which two hashes differ, weighting each byte twice as much as the
+    /// one below it, so a differing first letter outweighs differences at the end of a name.
+    pub fn distance(hash1: u64, hash2: u64) -> u32 {
+        let difference = (hash1 ^ hash2).to_le_bytes();
+
+        difference
+            .iter()
+            .enumerate()
+            .map(|(i, byte)| byte.count_ones() << i)
+            .sum()
+    }
+
+    /// Counts the bits in which two hashes differ, all weighted equally.
+    pub fn hamming(hash1: u64, hash2: u64) -> u32 {
+        (hash1 ^ hash2).count_ones()
+    }
+}
+
+impl Default for Eudex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PhoneticEncoder for Eudex {
+    fn encode(&self, val: &str) -> Result<String, PhoneticError> {
+        Eudex::encode(self, val)
+    }
+
+    fn compare(&self, val1: &str, val2: &str) -> Result<bool, PhoneticError> {
+        Eudex::compare(self, val1, val2)
+    }
+
+    /// One minus the [`Eudex::distance`] of the hashes over its largest possible value.
+    fn similarity(&self, val1: &str, val2: &str) -> Result<f64, PhoneticError> {
+        let distance = Self::distance(self.hash(val1)?, self.hash(val2)?);
+        Ok(1.0 - f64::from(distance) / f64::from(MAX_DISTANCE))
+    }
+
+    fn name(&self) -> &'static str {
+        "eudex"
+    }
+
+    fn max_code_length(&self) -> Option<usize> {
+        Some(16)
+    }
+}
@@ -0,0 +1,96 @@
+use std::ops::Range;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExplainStage {
+    /// Case folding, removal of non-letters and of repeated letters.
+    Preprocess,
+    /// Rewrites of the start of the name.
+    FirstCharacters,
+    /// Rewrites of the end of the name.
+    LastCharacters,
+    /// The main pass producing the code.
+    Transcode,
+    /// Clean-up of the end of the code.
+    TrailingSuffix,
+    /// Truncation of the code to its maximum length.
+    Truncate,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExplainStep {
+    pub stage: ExplainStage,
+    pub rule: &'static str,
+    /// Char offsets of `input` within the text as it stood when the rule ran.
+    pub span: Range<usize>,
+    pub input: String,
+    pub emitted: String,
+    /// The characters of `input` that were dropped rather than rewritten.
+    pub skipped: String,
+}
+
+impl ExplainStep {
+    pub(crate) fn new(
+        stage: ExplainStage,
+        rule: &'static str,
+        span: Range<usize>,
+        input: impl Into<String>,
+        emitted: impl Into<String>,
+    ) -> Self {
+        let input: String = input.into();
+        let emitted: String = emitted.into();
+
+        // Characters carried into the output are not skipped; of the rest, as many as the
+        // output is shorter than the input count as dropped, taken from the end.
+        let mut unmatched: Vec<char> = input.chars().collect();
+        for c in emitted.chars() {
+            if let Some(position) = unmatched.iter().position(|&u| u == c) {
+                unmatched.remove(position);
+            }
+        }
+        let dropped = input
+            .chars()
+            .count()
+            .saturating_sub(emitted.chars().count());
+        let skipped = unmatched[unmatched.len().saturating_sub(dropped)..]
+            .iter()
+            .collect();
+
+        ExplainStep {
+            stage,
+            rule,
+            span,
+            input,
+            emitted,
+            skipped,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    pub code: String,
+    pub steps: Vec<ExplainStep>,
+}
+
+/// Collects trace steps when enabled; steps are only built when they will be kept.
+pub(crate) struct Tracer(Option<Vec<ExplainStep>>);
+
+impl Tracer {
+    pub(crate) fn enabled() -> Self {
+        Tracer(Some(Vec::new()))
+    }
+
+    pub(crate) fn disabled() -> Self {
+        Tracer(None)
+    }
+
+    pub(crate) fn record(&mut self, step: impl FnOnce() -> ExplainStep) {
+        if let Some(steps) = &mut self.0 {
+            steps.push(step());
+        }
+    }
+
+    pub(crate) fn into_steps(self) -> Vec<ExplainStep> {
+        self.0.unwrap_or_default()
+    }
+}
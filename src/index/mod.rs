@@ -0,0 +1,189 @@
+mod persist;
+
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use crate::encoder::PhoneticEncoder;
+use crate::error::PhoneticError;
+use crate::{Metaphone, Nysiis};
+
+pub use persist::{IndexFormatError, PersistedIndex};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlgorithmMatch {
+    pub algorithm: &'static str,
+    /// The code the query shared with the matched name.
+    pub code: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryHit<Id> {
+    pub id: Id,
+    /// One entry per encoder under which the id matched.
+    pub matches: Vec<AlgorithmMatch>,
+}
+
+struct Entry {
+    sequence: u64,
+    /// (encoder index, code) pairs the id is posted under.
+    codes: Vec<(usize, String)>,
+}
+
+/// An inverted index from phonetic codes to ids, kept for each of its encoders.
+///
+/// Encoders whose codes list alternatives separated by '|', such as [`crate::DaitchMokotoff`],
+/// are posted under each alternative.
+pub struct PhoneticIndex<Id = u64> {
+    encoders: Vec<Box<dyn PhoneticEncoder>>,
+    postings: Vec<HashMap<String, HashSet<Id>>>,
+    entries: HashMap<Id, Entry>,
+    next_sequence: u64,
+}
+
+impl<Id: Clone + Eq + Hash> PhoneticIndex<Id> {
+    /// Creates an empty index over [`Metaphone`] and [`Nysiis`] codes.
+    pub fn new() -> Self {
+        Self::with_encoders(vec![Box::new(Metaphone::new()), Box::new(Nysiis::new())])
+    }
+
+    pub fn with_encoders(encoders: Vec<Box<dyn PhoneticEncoder>>) -> Self {
+        PhoneticIndex {
+            postings: encoders.iter().map(|_| HashMap::new()).collect(),
+            encoders,
+            entries: HashMap::new(),
+            next_sequence: 0,
+        }
+    }
+
+    pub fn encoders(&self) -> impl Iterator<Item = &dyn PhoneticEncoder> {
+        self.encoders.iter().map(|encoder| encoder.as_ref())
+    }
+
+    /// Number of distinct ids in the index.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn contains(&self, id: &Id) -> bool {
+        self.entries.contains_key(id)
+    }
+
+    /// Indexes `name` under `id`. Inserting the same id again adds another name for it. Nothing
+    /// is indexed if any encoder rejects the name.
+    pub fn insert(&mut self, id: Id, name: &str) -> Result<(), PhoneticError> {
+        let codes = encode_all(&self.encoders, name)?;
+
+        let entry = self.entries.entry(id.clone()).or_insert_with(|| {
+            let sequence = self.next_sequence;
+            self.next_sequence += 1;
+            Entry {
+                sequence,
+                codes: Vec::new(),
+            }
+        });
+
+        for (encoder, code) in codes {
+            let ids = self.postings[encoder].entry(code.clone()).or_default();
+            if ids.insert(id.clone()) {
+                entry.codes.push((encoder, code));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes `id` and every name indexed under it. Returns whether it was present.
+    pub fn remove(&mut self, id: &Id) -> bool {
+        let Some(entry) = self.entries.remove(id) else {
+            return false;
+        };
+
+        for (encoder, code) in entry.codes {
+            if let Some(ids) = self.postings[encoder].get_mut(&code) {
+                ids.remove(id);
+                if ids.is_empty() {
+                    self.postings[encoder].remove(&code);
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Returns the ids sharing a code with `name` under at least one encoder, those matching
+    /// under the most encoders first and otherwise in insertion order.
+    pub fn query(&self, name: &str) -> Result<Vec<QueryHit<Id>>, PhoneticError> {
+        let mut hits = Hits::new();
+
+        for (encoder, code) in encode_all(&self.encoders, name)? {
+            if let Some(ids) = self.postings[encoder].get(&code) {
+                hits.add(ids.iter().cloned(), self.encoders[encoder].name(), &code);
+            }
+        }
+
+        let mut hits = hits.into_vec();
+        hits.sort_by_key(|hit| {
+            (
+                std::cmp::Reverse(hit.matches.len()),
+                self.entries[&hit.id].sequence,
+            )
+        });
+
+        Ok(hits)
+    }
+}
+
+impl<Id: Clone + Eq + Hash> Default for PhoneticIndex<Id> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Encodes `name` with every encoder, returning (encoder index, code) pairs with multi-code
+/// results split into their alternatives.
+fn encode_all(
+    encoders: &[Box<dyn PhoneticEncoder>],
+    name: &str,
+) -> Result<Vec<(usize, String)>, PhoneticError> {
+    let mut codes = Vec::new();
+
+    for (i, encoder) in encoders.iter().enumerate() {
+        let code = encoder.encode(name)?;
+        codes.extend(code.split('|').map(|code| (i, code.to_string())));
+    }
+
+    Ok(codes)
+}
+
+/// Gathers the matches of a query per id.
+struct Hits<Id>(HashMap<Id, Vec<AlgorithmMatch>>);
+
+impl<Id: Eq + Hash> Hits<Id> {
+    fn new() -> Self {
+        Hits(HashMap::new())
+    }
+
+    fn add(&mut self, ids: impl Iterator<Item = Id>, algorithm: &'static str, code: &str) {
+        for id in ids {
+            let matches = self.0.entry(id).or_default();
+            // A multi-code encoder may match the same id through several alternatives.
+            if matches.last().is_none_or(|m| m.algorithm != algorithm) {
+                matches.push(AlgorithmMatch {
+                    algorithm,
+                    code: code.to_string(),
+                });
+            }
+        }
+    }
+
+    fn into_vec(self) -> Vec<QueryHit<Id>> {
+        self.0
+            .into_iter()
+            .map(|(id, matches)| QueryHit { id, matches })
+            .collect()
+    }
+}
@@ -0,0 +1,424 @@
+//! A versioned binary format for [`PhoneticIndex`] that can be queried in place, e.g. from a
+//! memory-mapped file.
+//!
+//! All integers are little-endian. The file is a 24-byte header followed by the body:
+//!
+//! ```text
+//! header  magic "PHIX" | version u16 | encoder count u16 | body length u64 | CRC-32 of body u32
+//!         | reserved u32
+//! body    id count u32 | ids u64 * id count, in insertion order
+//!         then per encoder:
+//!         name length u16 | name | config length u16 | config | code count u32
+//!         | code table (code offset u32, code length u32, postings offset u32,
+//!           postings count u32) * code count, sorted by code
+//!         | data length u32 | data (codes, and postings as u32 positions in the id list)
+//! ```
+//!
+//! Offsets in a code table are relative to the start of the data that follows it.
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::HashSet;
+use std::fmt;
+use std::io::{self, Write};
+use std::ops::Range;
+
+use super::{Hits, PhoneticIndex, QueryHit, encode_all};
+use crate::encoder::PhoneticEncoder;
+use crate::error::PhoneticError;
+
+const MAGIC: &[u8; 4] = b"PHIX";
+const VERSION: u16 = 1;
+const HEADER_LEN: usize = 24;
+const CODE_ENTRY_LEN: usize = 16;
+
+#[derive(Debug)]
+pub enum IndexFormatError {
+    /// The data does not start with the index magic number.
+    BadMagic,
+    UnsupportedVersion(u16),
+    /// The body does not match its checksum.
+    ChecksumMismatch,
+    /// The data is truncated or internally inconsistent.
+    Corrupt(&'static str),
+    /// The encoders given to open the index differ from the ones it was built with. Encoders
+    /// are described as `name(config)`.
+    EncoderMismatch {
+        expected: Vec<String>,
+        found: Vec<String>,
+    },
+}
+
+impl fmt::Display for IndexFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IndexFormatError::BadMagic => write!(f, "not a phonetic index"),
+            IndexFormatError::UnsupportedVersion(version) => {
+                write!(f, "unsupported index format version {}", version)
+            }
+            IndexFormatError::ChecksumMismatch => write!(f, "index checksum mismatch"),
+            IndexFormatError::Corrupt(what) => write!(f, "corrupt index: {}", what),
+            IndexFormatError::EncoderMismatch { expected, found } => write!(
+                f,
+                "index was built with encoders [{}] but opened with [{}]",
+                expected.join(", "),
+                found.join(", ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for IndexFormatError {}
+
+fn describe(encoder: &dyn PhoneticEncoder) -> String {
+    format!("{}({})", encoder.name(), encoder.config())
+}
+
+impl PhoneticIndex<u64> {
+    /// Writes the index in the format read by [`PersistedIndex`]. Each encoder's name and
+    /// [`PhoneticEncoder::config`] are stored so that it can only be reopened with the same
+    /// encoders.
+    pub fn write_to<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let too_large = || io::Error::new(io::ErrorKind::InvalidInput, "index too large to write");
+
+        let mut ids: Vec<(u64, u64)> = self
+            .entries
+            .iter()
+            .map(|(id, entry)| (entry.sequence, *id))
+            .collect();
+        ids.sort_unstable();
+        let positions: std::collections::HashMap<u64, u32> = ids
+            .iter()
+            .enumerate()
+            .map(|(position, &(_, id))| (id, position as u32))
+            .collect();
+
+        let mut body = Vec::new();
+        put_u32(&mut body, ids.len()).ok_or_else(too_large)?;
+        for (_, id) in &ids {
+            body.extend_from_slice(&id.to_le_bytes());
+        }
+
+        for (encoder, postings) in self.encoders.iter().zip(&self.postings) {
+            put_str(&mut body, encoder.name()).ok_or_else(too_large)?;
+            put_str(&mut body, &encoder.config()).ok_or_else(too_large)?;
+
+            let mut codes: Vec<(&String, &HashSet<u64>)> = postings.iter().collect();
+            codes.sort_unstable_by(|a, b| a.0.as_bytes().cmp(b.0.as_bytes()));
+            put_u32(&mut body, codes.len()).ok_or_else(too_large)?;
+
+            let mut data = Vec::new();
+            for (code, ids) in codes {
+                put_u32(&mut body, data.len()).ok_or_else(too_large)?;
+                put_u32(&mut body, code.len()).ok_or_else(too_large)?;
+                data.extend_from_slice(code.as_bytes());
+
+                let mut ids: Vec<u32> = ids.iter().map(|id| positions[id]).collect();
+                ids.sort_unstable();
+                put_u32(&mut body, data.len()).ok_or_else(too_large)?;
+                put_u32(&mut body, ids.len()).ok_or_else(too_large)?;
+                for position in ids {
+                    data.extend_from_slice(&position.to_le_bytes());
+                }
+            }
+
+            put_u32(&mut body, data.len()).ok_or_else(too_large)?;
+            body.extend_from_slice(&data);
+        }
+
+        let encoder_count = u16::try_from(self.encoders.len()).map_err(|_| too_large())?;
+
+        let mut header = Vec::with_capacity(HEADER_LEN);
+        header.extend_from_slice(MAGIC);
+        header.extend_from_slice(&VERSION.to_le_bytes());
+        header.extend_from_slice(&encoder_count.to_le_bytes());
+        header.extend_from_slice(&(body.len() as u64).to_le_bytes());
+        header.extend_from_slice(&crc32fast::hash(&body).to_le_bytes());
+        header.extend_from_slice(&0u32.to_le_bytes());
+
+        writer.write_all(&header)?;
+        writer.write_all(&body)?;
+        writer.flush()
+    }
+}
+
+/// Appends `value` as a u32, or returns `None` if it does not fit.
+fn put_u32(out: &mut Vec<u8>, value: usize) -> Option<()> {
+    out.extend_from_slice(&u32::try_from(value).ok()?.to_le_bytes());
+    Some(())
+}
+
+fn put_str(out: &mut Vec<u8>, value: &str) -> Option<()> {
+    out.extend_from_slice(&u16::try_from(value.len()).ok()?.to_le_bytes());
+    out.extend_from_slice(value.as_bytes());
+    Some(())
+}
+
+/// Reads the fields of the format from a byte slice, failing on truncation.
+struct Cursor<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn take(&mut self, len: usize) -> Result<Range<usize>, IndexFormatError> {
+        let end = self
+            .position
+            .checked_add(len)
+            .filter(|&end| end <= self.data.len())
+            .ok_or(IndexFormatError::Corrupt("unexpected end of data"))?;
+        let range = self.position..end;
+        self.position = end;
+        Ok(range)
+    }
+
+    fn u16(&mut self) -> Result<u16, IndexFormatError> {
+        let range = self.take(2)?;
+        Ok(u16::from_le_bytes(self.data[range].try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> Result<usize, IndexFormatError> {
+        let range = self.take(4)?;
+        Ok(u32::from_le_bytes(self.data[range].try_into().unwrap()) as usize)
+    }
+
+    fn str(&mut self) -> Result<&'a str, IndexFormatError> {
+        let len = self.u16()? as usize;
+        let range = self.take(len)?;
+        std::str::from_utf8(&self.data[range])
+            .map_err(|_| IndexFormatError::Corrupt("invalid UTF-8 in encoder description"))
+    }
+}
+
+fn read_u32(data: &[u8], at: usize) -> usize {
+    u32::from_le_bytes(data[at..at + 4].try_into().unwrap()) as usize
+}
+
+/// Where one encoder's code table and data lie within the index.
+struct Section {
+    table: usize,
+    code_count: usize,
+    data: Range<usize>,
+}
+
+impl Section {
+    /// Returns (code, postings) byte ranges of the `i`th code table entry, or `None` if they
+    /// do not lie within the section's data.
+    fn entry(&self, bytes: &[u8], i: usize) -> Option<(Range<usize>, Range<usize>)> {
+        let at = self.table + i * CODE_ENTRY_LEN;
+        let code = read_u32(bytes, at);
+        let code_end = code.checked_add(read_u32(bytes, at + 4))?;
+        let postings = read_u32(bytes, at + 8);
+        let postings_end = read_u32(bytes, at + 12)
+            .checked_mul(4)?
+            .checked_add(postings)?;
+        if code_end > self.data.len() || postings_end > self.data.len() {
+            return None;
+        }
+
+        Some((
+            self.data.start + code..self.data.start + code_end,
+            self.data.start + postings..self.data.start + postings_end,
+        ))
+    }
+}
+
+/// A [`PhoneticIndex`] written by [`PhoneticIndex::write_to`], queried directly from its
+/// serialized bytes.
+///
+/// [`PersistedIndex::open`] checks the whole index before it is queried.
+/// [`PersistedIndex::open_unverified`] reads only the header and where each encoder's section
+/// lies, for a large memory-mapped file that should not be read in full up front.
+pub struct PersistedIndex<B> {
+    data: B,
+    checksum: u32,
+    encoders: Vec<Box<dyn PhoneticEncoder>>,
+    ids: Range<usize>,
+    sections: Vec<Section>,
+}
+
+impl<B: AsRef<[u8]>> PersistedIndex<B> {
+    /// Opens an index that must have been built with `encoders`, in the same order and with the
+    /// same configuration, and checks it as [`PersistedIndex::verify`] does.
+    pub fn open(
+        data: B,
+        encoders: Vec<Box<dyn PhoneticEncoder>>,
+    ) -> Result<Self, IndexFormatError> {
+        let index = Self::open_unverified(data, encoders)?;
+        index.verify()?;
+        Ok(index)
+    }
+
+    /// Like [`PersistedIndex::open`], but without reading the body past the layout of its
+    /// sections. Until [`PersistedIndex::verify`] succeeds, a query that reaches a damaged
+    /// entry finds nothing there rather than failing.
+    pub fn open_unverified(
+        data: B,
+        encoders: Vec<Box<dyn PhoneticEncoder>>,
+    ) -> Result<Self, IndexFormatError> {
+        let bytes = data.as_ref();
+
+        if bytes.len() < MAGIC.len() || &bytes[..MAGIC.len()] != MAGIC {
+            return Err(IndexFormatError::BadMagic);
+        }
+        let mut cursor = Cursor {
+            data: bytes,
+            position: MAGIC.len(),
+        };
+        let version = cursor.u16()?;
+        if version != VERSION {
+            return Err(IndexFormatError::UnsupportedVersion(version));
+        }
+        let encoder_count = cursor.u16()? as usize;
+        let body_len = cursor.take(8)?;
+        let body_len = u64::from_le_bytes(bytes[body_len].try_into().unwrap());
+        let checksum = cursor.u32()? as u32;
+        cursor.take(4)?;
+
+        if usize::try_from(body_len).ok() != Some(bytes.len() - HEADER_LEN) {
+            return Err(IndexFormatError::Corrupt(
+                "body length does not match the data",
+            ));
+        }
+
+        let id_count = cursor.u32()?;
+        let ids = cursor.take(id_count * 8)?;
+
+        let mut expected = Vec::with_capacity(encoder_count);
+        let mut sections = Vec::with_capacity(encoder_count);
+        for _ in 0..encoder_count {
+            let name = cursor.str()?;
+            let config = cursor.str()?;
+            expected.push(format!("{}({})", name, config));
+
+            let code_count = cursor.u32()?;
+            let table = cursor.take(code_count * CODE_ENTRY_LEN)?.start;
+            let data_len = cursor.u32()?;
+            let data = cursor.take(data_len)?;
+            sections.push(Section {
+                table,
+                code_count,
+                data,
+            });
+        }
+        if cursor.position != bytes.len() {
+            return Err(IndexFormatError::Corrupt(
+                "trailing data after the last encoder",
+            ));
+        }
+
+        let found: Vec<String> = encoders.iter().map(|e| describe(e.as_ref())).collect();
+        if found != expected {
+            return Err(IndexFormatError::EncoderMismatch { expected, found });
+        }
+
+        Ok(PersistedIndex {
+            data,
+            checksum,
+            encoders,
+            ids,
+            sections,
+        })
+    }
+
+    /// Checks the body against its checksum and the code tables of every encoder, reading the
+    /// whole index.
+    pub fn verify(&self) -> Result<(), IndexFormatError> {
+        let bytes = self.data.as_ref();
+        if crc32fast::hash(&bytes[HEADER_LEN..]) != self.checksum {
+            return Err(IndexFormatError::ChecksumMismatch);
+        }
+
+        for section in &self.sections {
+            check_section(bytes, section, self.len())?;
+        }
+
+        Ok(())
+    }
+
+    /// Number of distinct ids in the index.
+    pub fn len(&self) -> usize {
+        self.ids.len() / 8
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Returns the same hits, in the same order, as [`PhoneticIndex::query`] on the index that
+    /// was written.
+    pub fn query(&self, name: &str) -> Result<Vec<QueryHit<u64>>, PhoneticError> {
+        let bytes = self.data.as_ref();
+        let mut hits = Hits::new();
+
+        for (encoder, code) in encode_all(&self.encoders, name)? {
+            let section = &self.sections[encoder];
+            if let Some(postings) = find_code(bytes, section, code.as_bytes()) {
+                let positions = bytes[postings]
+                    .chunks_exact(4)
+                    .map(|position| u32::from_le_bytes(position.try_into().unwrap()) as usize)
+                    .filter(|&position| position < self.len());
+                hits.add(positions, self.encoders[encoder].name(), &code);
+            }
+        }
+
+        let mut hits = hits.into_vec();
+        // Positions in the id list follow insertion order.
+        hits.sort_by_key(|hit| (Reverse(hit.matches.len()), hit.id));
+
+        Ok(hits
+            .into_iter()
+            .map(|hit| QueryHit {
+                id: self.id_at(hit.id),
+                matches: hit.matches,
+            })
+            .collect())
+    }
+
+    fn id_at(&self, position: usize) -> u64 {
+        let at = self.ids.start + position * 8;
+        u64::from_le_bytes(self.data.as_ref()[at..at + 8].try_into().unwrap())
+    }
+}
+
+/// Checks that every code table entry points inside its data, that codes are sorted so they can
+/// be binary searched, and that postings refer to ids in the id list.
+fn check_section(bytes: &[u8], section: &Section, id_count: usize) -> Result<(), IndexFormatError> {
+    let mut previous: Option<&[u8]> = None;
+
+    for i in 0..section.code_count {
+        let Some((code, postings)) = section.entry(bytes, i) else {
+            return Err(IndexFormatError::Corrupt("code table entry out of bounds"));
+        };
+        let code = &bytes[code];
+        if previous.is_some_and(|previous| previous >= code) {
+            return Err(IndexFormatError::Corrupt("code table is not sorted"));
+        }
+        previous = Some(code);
+
+        if bytes[postings]
+            .chunks_exact(4)
+            .any(|position| u32::from_le_bytes(position.try_into().unwrap()) as usize >= id_count)
+        {
+            return Err(IndexFormatError::Corrupt("posting refers to an unknown id"));
+        }
+    }
+
+    Ok(())
+}
+
+/// Binary searches a section's code table, returning the byte range of the code's postings.
+fn find_code(bytes: &[u8], section: &Section, code: &[u8]) -> Option<Range<usize>> {
+    let (mut low, mut high) = (0, section.code_count);
+
+    while low < high {
+        let middle = low + (high - low) / 2;
+        let (candidate, postings) = section.entry(bytes, middle)?;
+        match bytes[candidate].cmp(code) {
+            Ordering::Less => low = middle + 1,
+            Ordering::Greater => high = middle,
+            Ordering::Equal => return Some(postings),
+        }
+    }
+
+    None
+}
@@ -0,0 +1,433 @@
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
+
+use crate::encoder::PhoneticEncoder;
+use crate::error::{PhoneticError, is_latin, validate_letters};
+
+const VIRAMA: char = '\u{094D}';
+const NUKTA: char = '\u{093C}';
+
+const MACRON: char = '\u{0304}';
+const DOT_ABOVE: char = '\u{0307}';
+const TILDE: char = '\u{0303}';
+const DOT_BELOW: char = '\u{0323}';
+const RING_BELOW: char = '\u{0325}';
+
+/// Brahmic scripts accepted by [`IndicPhonetic`] besides Latin romanizations.
+///
+/// Their Unicode blocks share the layout of Devanagari, so each letter is read as the
+/// Devanagari letter in the same position; Tamil letters, which do not mark voicing, read as
+/// voiceless consonants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndicScript {
+    Devanagari,
+    Bengali,
+    Gurmukhi,
+    Gujarati,
+    Oriya,
+    Tamil,
+    Telugu,
+    Kannada,
+    Malayalam,
+}
+
+impl IndicScript {
+    fn name(self) -> &'static str {
+        match self {
+            IndicScript::Devanagari => "devanagari",
+            IndicScript::Bengali => "bengali",
+            IndicScript::Gurmukhi => "gurmukhi",
+            IndicScript::Gujarati => "gujarati",
+            IndicScript::Oriya => "oriya",
+            IndicScript::Tamil => "tamil",
+            IndicScript::Telugu => "telugu",
+            IndicScript::Kannada => "kannada",
+            IndicScript::Malayalam => "malayalam",
+        }
+    }
+
+    fn block_start(self) -> u32 {
+        0x0900 + 0x80 * self as u32
+    }
+
+    fn contains(self, c: char) -> bool {
+        c as u32 & !0x7F == self.block_start()
+    }
+}
+
+/// Phonetic keys for names of the Indian subcontinent, written in Devanagari (optionally
+/// other Brahmic scripts) or romanized as ISO 15919, Hunterian or informal spellings, so
+/// that "Shrivastava", "Srivastav" and "श्रीवास्तव" share the key "SRIVASTAV".
+///
+/// Aspirated consonants fold into plain ones, retroflex into dental, the three sibilants into
+/// "S" and "w" into "v"; vowel length is ignored. Schwas are deleted as in Hindi, at the end
+/// of a word and between two vowel–consonant pairs, and doubled consonants are written once.
+pub struct IndicPhonetic {
+    scripts: Vec<IndicScript>,
+}
+
+impl IndicPhonetic {
+    /// Accepts Latin letters and Devanagari.
+    pub fn new() -> Self {
+        IndicPhonetic {
+            scripts: vec![IndicScript::Devanagari],
+        }
+    }
+
+    /// Also accepts letters of `script`.
+    pub fn with_script(mut self, script: IndicScript) -> Self {
+        if !self.scripts.contains(&script) {
+            self.scripts.push(script);
+        }
+        self
+    }
+
+    fn supports(&self, c: char) -> bool {
+        self.scripts.iter().any(|script| script.contains(c))
+    }
+
+    pub fn encode(&self, val: &str) -> Result<String, PhoneticError> {
+        validate_letters(val, |_, c| {
+            is_latin(c) || self.supports(c) || ('\u{0300}'..='\u{036F}').contains(&c)
+        })?;
+
+        // A value is read in the script of its first letter; letters of another script, as in
+        // "Ram राम", are not read alongside it.
+        let brahmic = val
+            .chars()
+            .find(|&c| !is_combining_mark(c))
+            .is_some_and(|c| self.supports(c));
+        if let Some((position, c)) = val
+            .chars()
+            .enumerate()
+            .find(|&(_, c)| !is_combining_mark(c) && self.supports(c) != brahmic)
+        {
+            return Err(PhoneticError::UnsupportedScript { position, char: c });
+        }
+
+        let phones = if val.chars().any(|c| self.supports(c)) {
+            brahmic_phones(&to_devanagari(val))
+        } else {
+            latin_phones(val)
+        };
+        // Signs without a letter to carry them, such as a lone virama, have no sound.
+        if phones.is_empty() {
+            return Err(PhoneticError::NonAlphabetic {
+                position: 0,
+                char: val.chars().next().unwrap_or_default(),
+            });
+        }
+
+        Ok(normalize(phones))
+    }
+
+    pub fn compare(&self, val1: &str, val2: &str) -> Result<bool, PhoneticError> {
+        Ok(self.encode(val1)? == self.encode(val2)?)
+    }
+}
+
+impl Default for IndicPhonetic {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn is_vowel(phone: char) -> bool {
+    matches!(phone, 'A' | 'E' | 'I' | 'O' | 'U')
+}
+
+/// Rewrites letters of any Brahmic block as their Devanagari counterparts.
+fn to_devanagari(val: &str) -> Vec<char> {
+    let mut text = Vec::new();
+
+    for c in val.chars() {
+        match c {
+            // Gurmukhi tippi is a nasal; addak doubles the next consonant.
+            '\u{0A70}' => text.push('\u{0902}'),
+            '\u{0A71}' => {}
+            // Bengali khanda ta and the Malayalam chillu letters are consonants without a vowel.
+            '\u{09CE}' => text.extend(['त', VIRAMA]),
+            '\u{0D7A}' => text.extend(['ण', VIRAMA]),
+            '\u{0D7B}' => text.extend(['न', VIRAMA]),
+            '\u{0D7C}' => text.extend(['र', VIRAMA]),
+            '\u{0D7D}' => text.extend(['ल', VIRAMA]),
+            '\u{0D7E}' => text.extend(['ळ', VIRAMA]),
+            '\u{0D7F}' => text.extend(['क', VIRAMA]),
+            '\u{0980}'..='\u{0D7F}' => {
+                text.extend(char::from_u32(0x0900 | (c as u32 & 0x7F)));
+            }
+            _ => text.push(c),
+        }
+    }
+
+    text
+}
+
+fn consonant(c: char) -> Option<char> {
+    Some(match c {
+        'क' | 'ख' | '\u{0958}' | '\u{0959}' => 'K',
+        'ग' | 'घ' | '\u{095A}' => 'G',
+        'च' | 'छ' => 'C',
+        'ज' | 'झ' | '\u{095B}' => 'J',
+        'ट' | 'ठ' | 'त' | 'थ' => 'T',
+        'ड' | 'ढ' | 'द' | 'ध' => 'D',
+        // The flapped "ड़" and "ढ़" are heard, and usually romanized, as "r".
+        '\u{095C}' | '\u{095D}' | 'र' | 'ऱ' => 'R',
+        'ङ' | 'ञ' | 'ण' | 'न' | 'ऩ' => 'N',
+        'प' | 'फ' | '\u{095E}' => 'P',
+        'ब' | 'भ' => 'B',
+        'म' => 'M',
+        'य' | '\u{095F}' => 'Y',
+        'ल' | 'ळ' | 'ऴ' => 'L',
+        'व' => 'V',
+        'श' | 'ष' | 'स' => 'S',
+        'ह' => 'H',
+        _ => return None,
+    })
+}
+
+/// Phones of an independent vowel or a vowel sign.
+fn vowel(c: char) -> Option<&'static str> {
+    Some(match c {
+        'अ' | 'आ' | 'ा' => "A",
+        'इ' | 'ई' | 'ि' | 'ी' => "I",
+        'उ' | 'ऊ' | 'ु' | 'ू' => "U",
+        'ऋ' | 'ॠ' | 'ृ' | 'ॄ' => "RI",
+        'ऌ' | 'ॡ' | 'ॢ' | 'ॣ' => "LI",
+        'ऍ' | 'ऎ' | 'ए' | 'ऐ' | 'ॅ' | 'ॆ' | 'े' | 'ै' => "E",
+        'ऑ' | 'ऒ' | 'ओ' | 'औ' | 'ॉ' | 'ॊ' | 'ो' | 'ौ' => "O",
+        _ => return None,
+    })
+}
+
+fn brahmic_phones(text: &[char]) -> Vec<char> {
+    let mut phones = Vec::with_capacity(text.len() * 2);
+    // Whether the last phone is the vowel a consonant carries when no sign follows it.
+    let mut inherent = false;
+    let mut i = 0;
+
+    while i < text.len() {
+        let c = text[i];
+        i += 1;
+        inherent = false;
+
+        if let Some(mut phone) = consonant(c) {
+            if text.get(i) == Some(&NUKTA) {
+                if matches!(c, 'ड' | 'ढ') {
+                    phone = 'R';
+                }
+                i += 1;
+            }
+            // "ज्ञ" is pronounced, and mostly romanized, as "gy".
+            if c == 'ज' && text.get(i) == Some(&VIRAMA) && text.get(i + 1) == Some(&'ञ') {
+                phones.extend(['G', 'Y']);
+                i += 2;
+            } else {
+                phones.push(phone);
+            }
+
+            match text.get(i) {
+                Some(&VIRAMA) => i += 1,
+                Some(&sign) if is_vowel_sign(sign) => {
+                    phones.extend(vowel(sign).unwrap_or_default().chars());
+                    i += 1;
+                }
+                _ => {
+                    phones.push('A');
+                    inherent = true;
+                }
+            }
+        } else if let Some(vowel) = vowel(c) {
+            phones.extend(vowel.chars());
+        } else {
+            match c {
+                // Anusvara; a chandrabindu only nasalizes the vowel and is rarely romanized.
+                '\u{0902}' => phones.push('N'),
+                '\u{0903}' => phones.push('H'),
+                _ => {}
+            }
+        }
+    }
+
+    // A final inherent vowel is silent unless it is the only vowel of the word.
+    if inherent && phones[..phones.len() - 1].iter().any(|&p| is_vowel(p)) {
+        phones.pop();
+    }
+
+    phones
+}
+
+fn is_vowel_sign(c: char) -> bool {
+    matches!(c, '\u{093E}'..='\u{094C}' | '\u{0962}' | '\u{0963}')
+}
+
+fn latin_phones(val: &str) -> Vec<char> {
+    // Each letter with the one mark that matters to it, if any.
+    let mut letters: Vec<(char, Option<char>)> = Vec::new();
+    // Whether the spelling marks long vowels and other distinctions, as ISO 15919 does.
+    let mut scholarly = false;
+    for c in val.nfd() {
+        scholarly |= is_combining_mark(c);
+        match c {
+            MACRON | DOT_ABOVE | TILDE | DOT_BELOW | RING_BELOW => {
+                if let Some(last) = letters.last_mut() {
+                    last.1 = Some(c);
+                }
+            }
+            c if c.is_ascii_alphabetic() => letters.push((c.to_ascii_lowercase(), None)),
+            _ => {}
+        }
+    }
+
+    let letter = |j: usize| letters.get(j).map(|l| l.0);
+    let vowel_at = |j: usize| letter(j).is_some_and(|c| matches!(c, 'a' | 'e' | 'i' | 'o' | 'u'));
+
+    let mut phones = Vec::with_capacity(letters.len());
+    let mut i = 0;
+    while i < letters.len() {
+        let (c, mark) = letters[i];
+        let next = letter(i + 1);
+        let aspirated = next == Some('h');
+
+        let (phone, length) = match c {
+            'a' => match next {
+                Some('a') => ("A", 2),
+                Some('i') => ("E", 2),
+                Some('u') => ("O", 2),
+                _ => ("A", 1),
+            },
+            'e' => match next {
+                Some('e') => ("I", 2),
+                Some('i') => ("E", 2),
+                _ => ("E", 1),
+            },
+            'i' if next == Some('i') => ("I", 2),
+            'i' => ("I", 1),
+            'o' => match next {
+                Some('o') => ("U", 2),
+                Some('u') => ("O", 2),
+                _ => ("O", 1),
+            },
+            'u' if next == Some('u') => ("U", 2),
+            'u' => ("U", 1),
+            // ISO 15919 writes the vowel "ऋ" as "r̥", IAST as "ṛ", which ISO keeps for the
+            // flapped "ड़" that is always followed by a vowel.
+            'r' if mark == Some(RING_BELOW) || (mark == Some(DOT_BELOW) && !vowel_at(i + 1)) => {
+                ("RI", 1)
+            }
+            'm' if matches!(mark, Some(DOT_ABOVE | DOT_BELOW)) => ("N", 1),
+            'j' if letters.get(i + 1) == Some(&('n', Some(TILDE))) => ("GY", 2),
+            'y' if vowel_at(i + 1) || i == 0 || vowel_at(i - 1) => ("Y", 1),
+            'y' => ("I", 1),
+            'c' if aspirated && letter(i + 2) == Some('h') => ("C", 3),
+            'x' => ("KS", 1),
+            'q' => ("K", 1),
+            'f' => ("P", 1),
+            'z' => ("J", 1),
+            'v' | 'w' => ("V", 1),
+            'h' => ("H", 1),
+            'b' | 'c' | 'd' | 'g' | 'j' | 'k' | 'p' | 'r' | 's' | 't' if aspirated => (plain(c), 2),
+            _ => (plain(c), 1),
+        };
+
+        phones.extend(phone.chars());
+        i += length;
+    }
+
+    // A scholarly spelling writes a long final "ā" apart from the inherent vowel, which is
+    // silent as in the script; in other spellings the two look alike and are left to
+    // `normalize`.
+    if scholarly
+        && let [rest @ .., consonant, 'A'] = phones.as_slice()
+        && letters
+            .last()
+            .is_some_and(|&(c, mark)| c == 'a' && mark.is_none())
+        && !is_vowel(*consonant)
+        && rest.iter().any(|&p| is_vowel(p))
+    {
+        phones.pop();
+    }
+
+    phones
+}
+
+fn plain(c: char) -> &'static str {
+    match c {
+        'b' => "B",
+        'c' => "C",
+        'd' => "D",
+        'g' => "G",
+        'j' => "J",
+        'k' => "K",
+        'l' => "L",
+        'm' => "M",
+        'n' => "N",
+        'p' => "P",
+        'r' => "R",
+        's' => "S",
+        't' => "T",
+        _ => "",
+    }
+}
+
+/// Turns the phones of a name into its key; spellings of either kind go through the same
+/// steps, so a schwa dropped by one spelling and written by another is deleted from both.
+fn normalize(mut phones: Vec<char>) -> String {
+    phones.dedup_by(|a, b| a == b && !is_vowel(*a));
+
+    let consonant_at = |phones: &[char], j: usize| phones.get(j).is_some_and(|&p| !is_vowel(p));
+    let vowel_at = |phones: &[char], j: usize| phones.get(j).is_some_and(|&p| is_vowel(p));
+
+    // Hindi drops the "a" of a syllable between two vowel–consonant pairs, from the right.
+    for i in (2..phones.len().saturating_sub(2)).rev() {
+        if phones[i] == 'A'
+            && vowel_at(&phones, i - 2)
+            && consonant_at(&phones, i - 1)
+            && consonant_at(&phones, i + 1)
+            && vowel_at(&phones, i + 2)
+        {
+            phones.remove(i);
+        }
+    }
+
+    if let [rest @ .., consonant, 'A'] = phones.as_slice()
+        && !is_vowel(*consonant)
+        && rest.iter().any(|&p| is_vowel(p))
+    {
+        phones.pop();
+    }
+
+    // A nasal before a stop or fricative takes its place of articulation, which the script
+    // writes with the anusvara whether it sounds as "m" or "n".
+    for i in 0..phones.len() {
+        let next = phones.get(i + 1).copied();
+        if phones[i] == 'M'
+            && next.is_some_and(|n| {
+                matches!(n, 'B' | 'C' | 'D' | 'G' | 'H' | 'J' | 'K' | 'P' | 'S' | 'T')
+            })
+        {
+            phones[i] = 'N';
+        }
+    }
+
+    phones.into_iter().collect()
+}
+
+impl PhoneticEncoder for IndicPhonetic {
+    fn encode(&self, val: &str) -> Result<String, PhoneticError> {
+        IndicPhonetic::encode(self, val)
+    }
+
+    fn name(&self) -> &'static str {
+        "indic"
+    }
+
+    fn max_code_length(&self) -> Option<usize> {
+        None
+    }
+
+    fn config(&self) -> String {
+        let scripts: Vec<&str> = self.scripts.iter().map(|script| script.name()).collect();
+        format!("scripts={}", scripts.join("+"))
+    }
+}
@@ -1,5 +1,48 @@
+#[cfg(feature = "csv")]
+mod batch;
+mod caverphone;
+mod chinese;
+mod cologne;
+mod daitch_mokotoff;
+pub mod distance;
+mod double_metaphone;
+mod encoder;
+mod error;
+mod eudex;
+mod explain;
+mod index;
+mod indic;
+mod match_rating;
 mod metaphon;
+mod multilingual;
+mod name;
 mod nysiis;
+mod particle;
+mod similarity;
+mod soundex;
+mod transliterate;
 
+#[cfg(feature = "csv")]
+pub use batch::{BatchAnnotator, BatchError, BatchSummary, ColumnSelector};
+pub use caverphone::{Caverphone, CaverphoneVersion};
+pub use chinese::{ChinesePhonetic, ChineseRomanization};
+pub use cologne::ColognePhonetic;
+pub use daitch_mokotoff::DaitchMokotoff;
+pub use double_metaphone::{DoubleMetaphone, DoubleMetaphoneCode};
+pub use encoder::{ENCODER_NAMES, PhoneticEncoder, encoder_by_name};
+pub use error::PhoneticError;
+pub use eudex::Eudex;
+pub use explain::{ExplainStage, ExplainStep, Explanation};
+pub use index::{AlgorithmMatch, IndexFormatError, PersistedIndex, PhoneticIndex, QueryHit};
+pub use indic::{IndicPhonetic, IndicScript};
+pub use match_rating::{MatchRating, MatchRatingComparison};
 pub use metaphon::{Metaphone, metaphone};
-pub use nysiis::Nysiis;
+pub use multilingual::{Language, MultilingualPhonetic, NameType};
+pub use name::{EncodedName, NameEncoder, NameToken, TokenMode};
+pub use nysiis::{Nysiis, NysiisVariant};
+pub use particle::{NormalizedName, Particle, ParticleHandling, ParticleNormalizer};
+pub use similarity::{PhoneticSimilarity, SimilarityMeasure};
+pub use soundex::{RefinedSoundex, Soundex};
+pub use transliterate::{
+    ArabicRomanization, CyrillicLanguage, CyrillicScheme, GreekScheme, Transliterator,
+};
@@ -0,0 +1,132 @@
+use crate::encoder::PhoneticEncoder;
+use crate::error::{PhoneticError, validate_alphabetic};
+use crate::transliterate::ascii_letters;
+
+const CODEX_LENGTH: usize = 6;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatchRatingComparison {
+    pub codex1: String,
+    pub codex2: String,
+    /// Six minus the number of characters left unmatched in the longer codex, or 0 when the
+    /// codexes differ in length by three or more and are not compared at all.
+    pub similarity: usize,
+    /// The similarity the pair must reach to match, which depends on their combined length.
+    pub minimum_rating: usize,
+}
+
+impl MatchRatingComparison {
+    pub fn is_match(&self) -> bool {
+        self.similarity >= self.minimum_rating
+    }
+}
+
+pub struct MatchRating;
+
+impl MatchRating {
+    pub fn new() -> Self {
+        MatchRating
+    }
+
+    pub fn encode(&self, val: &str) -> Result<String, PhoneticError> {
+        validate_alphabetic(val)?;
+
+        let letters = ascii_letters(val)?;
+
+        let mut codex: Vec<char> = Vec::with_capacity(letters.len());
+        for (i, &c) in letters.iter().enumerate() {
+            // Vowels are dropped unless they start the name.
+            if i > 0 && matches!(c, 'A' | 'E' | 'I' | 'O' | 'U') {
+                continue;
+            }
+            // Doubled consonants are written once.
+            if codex.last() == Some(&c) {
+                continue;
+            }
+            codex.push(c);
+        }
+
+        if codex.len() > CODEX_LENGTH {
+            codex.drain(3..codex.len() - 3);
+        }
+
+        Ok(codex.into_iter().collect())
+    }
+
+    pub fn compare(&self, val1: &str, val2: &str) -> Result<MatchRatingComparison, PhoneticError> {
+        let codex1 = self.encode(val1)?;
+        let codex2 = self.encode(val2)?;
+
+        let minimum_rating = match codex1.len() + codex2.len() {
+            0..=4 => 5,
+            5..=7 => 4,
+            8..=11 => 3,
+            _ => 2,
+        };
+
+        let similarity = if codex1.len().abs_diff(codex2.len()) >= 3 {
+            0
+        } else {
+            CODEX_LENGTH.saturating_sub(self.unmatched(&codex1, &codex2))
+        };
+
+        Ok(MatchRatingComparison {
+            codex1,
+            codex2,
+            similarity,
+            minimum_rating,
+        })
+    }
+
+    /// Strikes out characters that agree at the same position counting from the start, then,
+    /// among the characters left, those that agree at the same position counting from the end.
+    /// Returns how many characters remain in whichever codex has more left.
+    fn unmatched(&self, codex1: &str, codex2: &str) -> usize {
+        let n1: Vec<char> = codex1.chars().collect();
+        let n2: Vec<char> = codex2.chars().collect();
+
+        let (mut left1, mut left2): (Vec<char>, Vec<char>) = (Vec::new(), Vec::new());
+        for i in 0..n1.len().max(n2.len()) {
+            match (n1.get(i), n2.get(i)) {
+                (Some(c1), Some(c2)) if c1 == c2 => {}
+                (c1, c2) => {
+                    left1.extend(c1);
+                    left2.extend(c2);
+                }
+            }
+        }
+
+        let matched = left1
+            .iter()
+            .rev()
+            .zip(left2.iter().rev())
+            .filter(|(c1, c2)| c1 == c2)
+            .count();
+
+        left1.len().max(left2.len()) - matched
+    }
+}
+
+impl Default for MatchRating {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PhoneticEncoder for MatchRating {
+    fn encode(&self, val: &str) -> Result<String, PhoneticError> {
+        MatchRating::encode(self, val)
+    }
+
+    fn compare(&self, val1: &str, val2: &str) -> Result<bool, PhoneticError> {
+        Ok(MatchRating::compare(self, val1, val2)?.is_match())
+    }
+
+    fn name(&self) -> &'static str {
+        "match_rating"
+    }
+
+    fn max_code_length(&self) -> Option<usize> {
+        Some(CODEX_LENGTH)
+    }
+}
@@ -1,6 +1,15 @@
+use unicode_normalization::UnicodeNormalization;
+
+use crate::encoder::PhoneticEncoder;
+use crate::error::{PhoneticError, is_latin, validate_letters};
+use crate::explain::{ExplainStage, ExplainStep, Explanation, Tracer};
+use crate::similarity::SimilarityMeasure;
+use crate::transliterate::Transliterator;
+
 pub struct Metaphone {
     vowels: Vec<char>,
     iey: Vec<char>,
+    transliterator: Transliterator,
 }
 
 impl Metaphone {
@@ -8,37 +17,65 @@ impl Metaphone {
         Metaphone {
             vowels: vec!['a', 'e', 'i', 'o', 'u'],
             iey: vec!['i', 'e', 'y'],
+            transliterator: Transliterator::new(),
         }
     }
 
-    pub fn encode(&self, val: &str) -> Result<String, String> {
-        if val.is_empty() || !self.is_alphabetic(val) {
-            return Err("String is empty or non-alphabetic.".to_string());
-        }
+    /// Sets how accented and other non-ASCII letters are rewritten before encoding.
+    pub fn with_transliterator(mut self, transliterator: Transliterator) -> Self {
+        self.transliterator = transliterator;
+        self
+    }
 
-        let deduplicated = self.de_duplicate(&val.to_lowercase());
-        let first_char_processed = self.transcode_first_character(&deduplicated);
-        let transcoded = self.transcode(&first_char_processed);
+    pub fn encode(&self, val: &str) -> Result<String, PhoneticError> {
+        self.encode_traced(val, &mut Tracer::disabled())
+    }
 
-        Ok(transcoded)
+    /// Encodes `val` and reports, in order, every rule that contributed to the code.
+    pub fn encode_explained(&self, val: &str) -> Result<Explanation, PhoneticError> {
+        let mut tracer = Tracer::enabled();
+        let code = self.encode_traced(val, &mut tracer)?;
+
+        Ok(Explanation {
+            code,
+            steps: tracer.into_steps(),
+        })
     }
 
-    pub fn compare(&self, val1: &str, val2: &str) -> Result<bool, String> {
-        let s1_is_alphabetic = self.is_alphabetic(val1);
-        let s2_is_alphabetic = self.is_alphabetic(val2);
+    fn encode_traced(&self, val: &str, tracer: &mut Tracer) -> Result<String, PhoneticError> {
+        // Letters written with combining marks are checked once composed, as the
+        // transliterator reads them.
+        let val: String = val.nfc().collect();
+        let chars: Vec<char> = val.chars().collect();
+        validate_letters(&val, |i, c| {
+            is_latin(c) || self.transliterator.supports_at(&chars, i)
+        })?;
 
-        if val1.is_empty() || !s1_is_alphabetic || val2.is_empty() || !s2_is_alphabetic {
-            return Err("Unable to Metaphone compare the two values.".to_string());
-        }
+        let transliterated = self.transliterator.transliterate_traced(&val, tracer);
+        let lowercased = transliterated.to_lowercase();
+        self.trace_duplicates(&lowercased, tracer);
+        let deduplicated = self.de_duplicate(&lowercased);
+        let first_char_processed = self.transcode_first_character(&deduplicated, tracer);
+        let transcoded = self.transcode(&first_char_processed, tracer);
+
+        Ok(transcoded)
+    }
 
+    pub fn compare(&self, val1: &str, val2: &str) -> Result<bool, PhoneticError> {
         let phonetic1 = self.encode(val1)?;
         let phonetic2 = self.encode(val2)?;
 
         Ok(phonetic1 == phonetic2)
     }
 
-    fn is_alphabetic(&self, val: &str) -> bool {
-        val.chars().all(|c| c.is_alphabetic())
+    /// Scores how alike the codes of `val1` and `val2` are, from 0 to 1, with `measure`.
+    pub fn similarity(
+        &self,
+        val1: &str,
+        val2: &str,
+        measure: SimilarityMeasure,
+    ) -> Result<f64, PhoneticError> {
+        Ok(measure.score(&self.encode(val1)?, &self.encode(val2)?))
     }
 
     pub fn de_duplicate(&self, val: &str) -> String {
@@ -54,85 +91,92 @@ impl Metaphone {
         result
     }
 
-    fn transcode_first_character(&self, s: &str) -> String {
-        let chars: Vec<char> = s.chars().collect();
+    /// Records each run of repeated letters that [`Metaphone::de_duplicate`] collapses.
+    fn trace_duplicates(&self, val: &str, tracer: &mut Tracer) {
+        let chars: Vec<char> = val.chars().collect();
+        let mut start = 0;
 
-        match chars.len() {
-            0 => String::new(),
-            1 => {
-                let first_letter = chars[0];
-                match first_letter {
-                    'x' => "s".to_string(),
-                    _ => s.to_string(),
-                }
+        while start < chars.len() {
+            let end = start
+                + chars[start..]
+                    .iter()
+                    .take_while(|&&c| c == chars[start])
+                    .count();
+            if end - start > 1 {
+                tracer.record(|| {
+                    ExplainStep::new(
+                        ExplainStage::Preprocess,
+                        "repeated letter",
+                        start..end,
+                        chars[start..end].iter().collect::<String>(),
+                        chars[start].to_string(),
+                    )
+                });
             }
-            _ => {
-                let letter1 = chars[0];
-                let letter2 = chars[1];
-                let rest_of_word1: String = chars[1..].iter().collect();
-                let rest_of_word2: String = if chars.len() > 2 {
-                    chars[2..].iter().collect()
-                } else {
-                    String::new()
-                };
-
-                match letter1 {
-                    'a' => match letter2 {
-                        'e' => rest_of_word1,
-                        _ => s.to_string(),
-                    },
-                    'g' | 'k' | 'p' => match letter2 {
-                        'n' => rest_of_word1,
-                        _ => s.to_string(),
-                    },
-                    'w' => match letter2 {
-                        'r' => rest_of_word1,
-                        'h' => format!("w{}", rest_of_word2),
-                        _ => s.to_string(),
-                    },
-                    'x' => format!("s{}", rest_of_word1),
-                    _ => s.to_string(),
-                }
+            start = end;
+        }
+    }
+
+    /// Returns the rule matching the start of `s`, how many characters it replaces and what
+    /// they are replaced with.
+    fn first_character_rule(&self, s: &str) -> Option<(&'static str, usize, &'static str)> {
+        let mut chars = s.chars();
+        let letter1 = chars.next()?;
+        let letter2 = chars.next();
+
+        match (letter1, letter2) {
+            ('a', Some('e')) => Some(("initial ae", 1, "")),
+            ('g' | 'k' | 'p', Some('n')) => Some(("initial gn/kn/pn", 1, "")),
+            ('w', Some('r')) => Some(("initial wr", 1, "")),
+            ('w', Some('h')) => Some(("initial wh", 2, "w")),
+            ('x', _) => Some(("initial x", 1, "s")),
+            _ => None,
+        }
+    }
+
+    fn transcode_first_character(&self, s: &str, tracer: &mut Tracer) -> String {
+        match self.first_character_rule(s) {
+            Some((rule, consumed, replacement)) => {
+                tracer.record(|| {
+                    ExplainStep::new(
+                        ExplainStage::FirstCharacters,
+                        rule,
+                        0..consumed,
+                        &s[..consumed],
+                        replacement,
+                    )
+                });
+                format!("{}{}", replacement, &s[consumed..])
             }
+            None => s.to_string(),
         }
     }
 
-    fn transcode(&self, s: &str) -> String {
+    fn transcode(&self, s: &str, tracer: &mut Tracer) -> String {
         let characters: Vec<char> = s.chars().collect();
         let size = characters.len();
 
         let mut p_count = 0;
-        let mut output = vec![' '; size * 2];
-        let mut o_count = 0;
+        let mut output = String::with_capacity(size * 2);
 
         while p_count < size {
             let current_char = characters[p_count];
-            let r_size = if p_count + 1 >= size {
-                0
-            } else {
-                size - (p_count + 1)
-            };
+            let r_size = size.saturating_sub(p_count + 1);
 
-            match current_char {
+            let (rule, emitted, consumed): (&'static str, String, usize) = match current_char {
                 'a' | 'e' | 'i' | 'o' | 'u' => {
                     if p_count == 0 {
-                        output[o_count] = current_char;
-                        o_count += 1;
+                        ("initial vowel", current_char.to_string(), 1)
+                    } else {
+                        ("vowel", String::new(), 1)
                     }
-                    p_count += 1;
-                }
-                'f' | 'j' | 'l' | 'm' | 'n' | 'r' => {
-                    output[o_count] = current_char;
-                    o_count += 1;
-                    p_count += 1;
                 }
+                'f' | 'j' | 'l' | 'm' | 'n' | 'r' => ("letter", current_char.to_string(), 1),
                 'b' => {
                     if p_count >= 1 && p_count + 1 >= size && characters[p_count - 1] == 'm' {
-                        p_count += 1;
+                        ("mb at end", String::new(), 1)
                     } else {
-                        output[o_count] = 'b';
-                        o_count += 1;
-                        p_count += 1;
+                        ("b", "b".into(), 1)
                     }
                 }
                 'c' => {
@@ -141,39 +185,24 @@ impl Metaphone {
                         && p_count >= 1
                         && characters[p_count - 1] == 's'
                     {
-                        output[o_count] = 'k';
-                        o_count += 1;
-                        p_count += 1;
+                        ("sch", "k".into(), 1)
                     } else if r_size >= 2
                         && characters[p_count + 1] == 'i'
                         && characters[p_count + 2] == 'a'
                     {
-                        output[o_count] = 'x';
-                        o_count += 1;
-                        p_count += 3;
-                    } else if (r_size >= 1 && characters[p_count + 1] == 'h')
-                        || (p_count >= 1
-                            && r_size >= 1
-                            && characters[p_count - 1] == 's'
-                            && characters[p_count + 1] == 'h')
-                    {
-                        output[o_count] = 'x';
-                        o_count += 1;
-                        p_count += 2;
+                        ("cia", "x".into(), 3)
+                    } else if r_size >= 1 && characters[p_count + 1] == 'h' {
+                        ("ch", "x".into(), 2)
                     } else if p_count >= 1
                         && r_size >= 1
                         && characters[p_count - 1] == 's'
                         && self.iey.contains(&characters[p_count + 1])
                     {
-                        p_count += 1;
+                        ("sci/sce/scy", String::new(), 1)
                     } else if r_size >= 1 && self.iey.contains(&characters[p_count + 1]) {
-                        output[o_count] = 's';
-                        o_count += 1;
-                        p_count += 1;
+                        ("ci/ce/cy", "s".into(), 1)
                     } else {
-                        output[o_count] = 'k';
-                        o_count += 1;
-                        p_count += 1;
+                        ("c", "k".into(), 1)
                     }
                 }
                 'd' => {
@@ -181,13 +210,9 @@ impl Metaphone {
                         && characters[p_count + 1] == 'g'
                         && self.iey.contains(&characters[p_count + 2])
                     {
-                        output[o_count] = 'j';
-                        o_count += 1;
-                        p_count += 1;
+                        ("dge/dgi/dgy", "j".into(), 1)
                     } else {
-                        output[o_count] = 't';
-                        o_count += 1;
-                        p_count += 1;
+                        ("d", "t".into(), 1)
                     }
                 }
                 'g' => {
@@ -197,15 +222,11 @@ impl Metaphone {
                             && characters[p_count + 1] == 'n'
                             && characters[p_count + 3] == 'd')
                     {
-                        p_count += 1;
+                        ("gh/gn/gned", String::new(), 1)
                     } else if r_size >= 1 && self.iey.contains(&characters[p_count + 1]) {
-                        output[o_count] = 'j';
-                        o_count += 1;
-                        p_count += 2;
+                        ("gi/ge/gy", "j".into(), 2)
                     } else {
-                        output[o_count] = 'k';
-                        o_count += 1;
-                        p_count += 1;
+                        ("g", "k".into(), 1)
                     }
                 }
                 'h' => {
@@ -216,54 +237,36 @@ impl Metaphone {
                             && characters[p_count - 1] == 'h'
                             && (characters[p_count - 2] == 't' || characters[p_count - 2] == 'g'))
                     {
-                        p_count += 1;
+                        ("silent h", String::new(), 1)
                     } else {
-                        output[o_count] = 'h';
-                        o_count += 1;
-                        p_count += 1;
+                        ("h", "h".into(), 1)
                     }
                 }
                 'k' => {
                     if p_count >= 1 && characters[p_count - 1] == 'c' {
-                        p_count += 1;
+                        ("ck", String::new(), 1)
                     } else {
-                        output[o_count] = 'k';
-                        o_count += 1;
-                        p_count += 1;
+                        ("k", "k".into(), 1)
                     }
                 }
                 'p' => {
                     if r_size >= 1 && characters[p_count + 1] == 'h' {
-                        output[o_count] = 'f';
-                        o_count += 1;
-                        p_count += 2;
+                        ("ph", "f".into(), 2)
                     } else {
-                        output[o_count] = 'p';
-                        o_count += 1;
-                        p_count += 1;
+                        ("p", "p".into(), 1)
                     }
                 }
-                'q' => {
-                    output[o_count] = 'k';
-                    o_count += 1;
-                    p_count += 1;
-                }
+                'q' => ("q", "k".into(), 1),
                 's' => {
                     if r_size >= 2
                         && characters[p_count + 1] == 'i'
                         && ['a', 'o'].contains(&characters[p_count + 2])
                     {
-                        output[o_count] = 'x';
-                        o_count += 1;
-                        p_count += 3;
+                        ("sia/sio", "x".into(), 3)
                     } else if r_size >= 1 && characters[p_count + 1] == 'h' {
-                        output[o_count] = 'x';
-                        o_count += 1;
-                        p_count += 2;
+                        ("sh", "x".into(), 2)
                     } else {
-                        output[o_count] = 's';
-                        o_count += 1;
-                        p_count += 1;
+                        ("s", "s".into(), 1)
                     }
                 }
                 't' => {
@@ -271,55 +274,47 @@ impl Metaphone {
                         && characters[p_count + 1] == 'i'
                         && ['a', 'o'].contains(&characters[p_count + 2])
                     {
-                        output[o_count] = 'x';
-                        o_count += 1;
-                        p_count += 3;
+                        ("tia/tio", "x".into(), 3)
                     } else if r_size >= 1 && characters[p_count + 1] == 'h' {
-                        output[o_count] = '0';
-                        o_count += 1;
-                        p_count += 2;
+                        ("th", "0".into(), 2)
                     } else if r_size >= 2
                         && characters[p_count + 1] == 'c'
                         && characters[p_count + 2] == 'h'
                     {
-                        p_count += 1;
+                        ("tch", String::new(), 1)
                     } else {
-                        output[o_count] = 't';
-                        o_count += 1;
-                        p_count += 1;
+                        ("t", "t".into(), 1)
                     }
                 }
-                'v' => {
-                    output[o_count] = 'f';
-                    o_count += 1;
-                    p_count += 1;
-                }
+                'v' => ("v", "f".into(), 1),
                 'w' | 'y' => {
                     if r_size == 0 || !self.vowels.contains(&characters[p_count + 1]) {
-                        p_count += 1;
+                        ("w/y not before vowel", String::new(), 1)
                     } else {
-                        output[o_count] = current_char;
-                        o_count += 1;
-                        p_count += 1;
+                        ("w/y before vowel", current_char.to_string(), 1)
                     }
                 }
-                'x' => {
-                    output[o_count] = 'k';
-                    output[o_count + 1] = 's';
-                    o_count += 2;
-                    p_count += 1;
-                }
-                'z' => {
-                    output[o_count] = 's';
-                    o_count += 1;
-                    p_count += 1;
-                }
-                _ => {
-                    p_count += 1;
-                }
-            }
+                'x' => ("x", "ks".into(), 1),
+                'z' => ("z", "s".into(), 1),
+                _ => ("other", String::new(), 1),
+            };
+
+            output.push_str(&emitted);
+            tracer.record(|| {
+                ExplainStep::new(
+                    ExplainStage::Transcode,
+                    rule,
+                    p_count..p_count + consumed,
+                    characters[p_count..p_count + consumed]
+                        .iter()
+                        .collect::<String>(),
+                    emitted,
+                )
+            });
+            p_count += consumed;
         }
-        output[0..o_count].iter().collect()
+
+        output
     }
 }
 
@@ -329,13 +324,41 @@ impl Default for Metaphone {
     }
 }
 
+impl PhoneticEncoder for Metaphone {
+    fn encode(&self, val: &str) -> Result<String, PhoneticError> {
+        Metaphone::encode(self, val)
+    }
+
+    fn compare(&self, val1: &str, val2: &str) -> Result<bool, PhoneticError> {
+        Metaphone::compare(self, val1, val2)
+    }
+
+    fn name(&self) -> &'static str {
+        "metaphone"
+    }
+
+    fn max_code_length(&self) -> Option<usize> {
+        None
+    }
+
+    fn config(&self) -> String {
+        self.transliterator.config()
+    }
+}
+
 pub mod metaphone {
     use super::Metaphone;
-    pub fn metaphone(val: &str) -> Result<String, String> {
+    use crate::error::PhoneticError;
+    use crate::similarity::SimilarityMeasure;
+    pub fn metaphone(val: &str) -> Result<String, PhoneticError> {
         Metaphone::new().encode(val)
     }
 
-    pub fn metaphone_metric(val1: &str, val2: &str) -> Result<bool, String> {
+    pub fn metaphone_metric(val1: &str, val2: &str) -> Result<bool, PhoneticError> {
         Metaphone::new().compare(val1, val2)
     }
+
+    pub fn metaphone_similarity(val1: &str, val2: &str) -> Result<f64, PhoneticError> {
+        Metaphone::new().similarity(val1, val2, SimilarityMeasure::JaroWinkler)
+    }
 }
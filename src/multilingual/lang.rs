@@ -0,0 +1,160 @@
+use regex::Regex;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Language {
+    English,
+    French,
+    German,
+    Hungarian,
+    Italian,
+    Polish,
+    Portuguese,
+    Romanian,
+    Russian,
+    Spanish,
+}
+
+impl Language {
+    pub const ALL: [Language; 10] = [
+        Language::English,
+        Language::French,
+        Language::German,
+        Language::Hungarian,
+        Language::Italian,
+        Language::Polish,
+        Language::Portuguese,
+        Language::Romanian,
+        Language::Russian,
+        Language::Spanish,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Language::English => "english",
+            Language::French => "french",
+            Language::German => "german",
+            Language::Hungarian => "hungarian",
+            Language::Italian => "italian",
+            Language::Polish => "polish",
+            Language::Portuguese => "portuguese",
+            Language::Romanian => "romanian",
+            Language::Russian => "russian",
+            Language::Spanish => "spanish",
+        }
+    }
+
+    pub(crate) fn bit(&self) -> u16 {
+        1 << (*self as u16)
+    }
+}
+
+pub(crate) const ENGLISH: u16 = 1 << Language::English as u16;
+pub(crate) const FRENCH: u16 = 1 << Language::French as u16;
+pub(crate) const GERMAN: u16 = 1 << Language::German as u16;
+pub(crate) const HUNGARIAN: u16 = 1 << Language::Hungarian as u16;
+pub(crate) const ITALIAN: u16 = 1 << Language::Italian as u16;
+pub(crate) const POLISH: u16 = 1 << Language::Polish as u16;
+pub(crate) const PORTUGUESE: u16 = 1 << Language::Portuguese as u16;
+pub(crate) const ROMANIAN: u16 = 1 << Language::Romanian as u16;
+pub(crate) const RUSSIAN: u16 = 1 << Language::Russian as u16;
+pub(crate) const SPANISH: u16 = 1 << Language::Spanish as u16;
+
+// Each rule is (pattern, languages, accept). A matching accepting rule narrows the candidate
+// languages to the listed ones; a matching rejecting rule removes them.
+const LANGUAGE_RULES: &[(&str, u16, bool)] = &[
+    // Polish
+    ("[ąęłńśźżć]", POLISH, true),
+    ("szcz|wicz$|wski$|wska$|owa$", POLISH, true),
+    ("rz", POLISH | GERMAN, true),
+    ("cz", POLISH | HUNGARIAN | GERMAN, true),
+    ("sz", POLISH | HUNGARIAN, true),
+    // Hungarian
+    ("[őű]", HUNGARIAN, true),
+    ("cs|zs|gy|ty$|ny$|^ny", HUNGARIAN, true),
+    // German
+    ("[äß]", GERMAN, true),
+    ("[öü]", GERMAN | HUNGARIAN, true),
+    (
+        "tsch|sch|tz|ck|dt$|mann$",
+        GERMAN | ENGLISH | POLISH | RUSSIAN,
+        true,
+    ),
+    ("sch", ENGLISH, false),
+    ("^sch|tsch", POLISH | RUSSIAN, false),
+    (
+        "ei|eu|ie",
+        GERMAN | FRENCH | ENGLISH | POLISH | ROMANIAN | SPANISH,
+        true,
+    ),
+    // Russian (romanized)
+    (
+        "shch|zh|kh|yev$|ov$|ev$|skiy$|skii$|vich$|vitch$",
+        RUSSIAN | ENGLISH,
+        true,
+    ),
+    ("^zh|shch|skiy$|skii$|yev$", ENGLISH, false),
+    // Romanian
+    ("[ăâîșşțţ]", ROMANIAN, true),
+    ("escu$|eanu$", ROMANIAN, true),
+    // French
+    ("[èêëçœ]", FRENCH | PORTUGUESE, true),
+    ("eau|ault$|eux$|oux$|^d'", FRENCH, true),
+    // Spanish and Portuguese
+    ("ñ", SPANISH, true),
+    ("[ãõ]|ão$|nh|lh", PORTUGUESE, true),
+    ("ez$|az$|oz$", SPANISH | PORTUGUESE, true),
+    ("ll", POLISH | RUSSIAN, false),
+    // Italian
+    ("cci|cce|zz|gli|ini$|etti$|ello$|elli$|ucci$", ITALIAN, true),
+    ("[àòù]", ITALIAN | FRENCH | PORTUGUESE, true),
+    // English
+    ("^mc|^mac|^o'|ough|augh|ght|tch$|th$", ENGLISH, true),
+    (
+        "w",
+        FRENCH | ITALIAN | SPANISH | PORTUGUESE | ROMANIAN,
+        false,
+    ),
+    ("k", ITALIAN | SPANISH | PORTUGUESE | FRENCH, false),
+    ("q", POLISH | RUSSIAN | HUNGARIAN, false),
+    ("x", POLISH | RUSSIAN, false),
+];
+
+pub(crate) struct LanguageGuesser {
+    rules: Vec<(Regex, u16, bool)>,
+}
+
+impl LanguageGuesser {
+    pub(crate) fn new() -> Self {
+        let rules = LANGUAGE_RULES
+            .iter()
+            .map(|(pattern, languages, accept)| (Regex::new(pattern).unwrap(), *languages, *accept))
+            .collect();
+
+        LanguageGuesser { rules }
+    }
+
+    /// Returns the subset of `candidates` consistent with the spelling of `name`, or 0 when no
+    /// candidate survives.
+    pub(crate) fn guess(&self, name: &str, candidates: u16) -> u16 {
+        let mut languages = candidates;
+
+        for (pattern, rule_languages, accept) in &self.rules {
+            if pattern.is_match(name) {
+                if *accept {
+                    languages &= rule_languages;
+                } else {
+                    languages &= !rule_languages;
+                }
+            }
+        }
+
+        languages
+    }
+}
+
+pub(crate) fn languages_from_bits(bits: u16) -> Vec<Language> {
+    Language::ALL
+        .into_iter()
+        .filter(|language| bits & language.bit() != 0)
+        .collect()
+}
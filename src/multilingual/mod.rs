@@ -0,0 +1,358 @@
+//! Multilingual phonetic matching after the design of Beider–Morse Phonetic Matching.
+//!
+//! Encoding follows the BMPM pipeline: the name is normalized according to its name type, the
+//! languages it may come from are guessed from its spelling, each candidate language's
+//! context-sensitive rules, preceded by those of the name type, turn it into one or more
+//! phonetic tokens, and a final set of approximation rules smooths over differences such as
+//! word-final devoicing. The rule tables are this crate's own, a condensed set covering ten
+//! European languages, so tokens are not those of BMPM implementations and the two should not
+//! be compared with each other.
+
+mod lang;
+mod rules;
+
+use std::collections::{BTreeSet, HashSet};
+
+use regex::Regex;
+
+use crate::encoder::PhoneticEncoder;
+use crate::error::{PhoneticError, is_latin};
+use lang::{
+    ENGLISH, FRENCH, GERMAN, HUNGARIAN, ITALIAN, LanguageGuesser, POLISH, PORTUGUESE, ROMANIAN,
+    RUSSIAN, SPANISH, languages_from_bits,
+};
+use rules::{COMMON, FINAL_APPROX, RuleDef, language_rules, name_type_rules};
+
+pub use lang::Language;
+
+/// Upper bound on the readings of one name under one language; a name with more is an error
+/// rather than a partial set of tokens that could miss a match.
+const MAX_BRANCHES: usize = 1024;
+
+const GENERIC_PREFIXES: &[&str] = &[
+    "d", "da", "dal", "de", "del", "dela", "della", "des", "di", "do", "dos", "du", "la", "le",
+    "van", "von",
+];
+const ASHKENAZI_PREFIXES: &[&str] = &["bar", "ben", "da", "de", "van", "von"];
+const SEPHARDIC_PREFIXES: &[&str] = &[
+    "al", "el", "da", "dal", "de", "del", "dela", "della", "des", "di", "do", "dos", "du", "la",
+    "van", "von",
+];
+
+/// The kind of names encoded. Each has its own languages, name particles and spelling rules,
+/// which take precedence over those of the languages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NameType {
+    Generic,
+    Ashkenazi,
+    Sephardic,
+}
+
+impl NameType {
+    fn languages(&self) -> u16 {
+        match self {
+            NameType::Generic => {
+                ENGLISH
+                    | FRENCH
+                    | GERMAN
+                    | HUNGARIAN
+                    | ITALIAN
+                    | POLISH
+                    | PORTUGUESE
+                    | ROMANIAN
+                    | RUSSIAN
+                    | SPANISH
+            }
+            NameType::Ashkenazi => {
+                ENGLISH | FRENCH | GERMAN | HUNGARIAN | POLISH | ROMANIAN | RUSSIAN | SPANISH
+            }
+            NameType::Sephardic => FRENCH | ITALIAN | PORTUGUESE | SPANISH,
+        }
+    }
+
+    fn prefixes(&self) -> &'static [&'static str] {
+        match self {
+            NameType::Generic => GENERIC_PREFIXES,
+            NameType::Ashkenazi => ASHKENAZI_PREFIXES,
+            NameType::Sephardic => SEPHARDIC_PREFIXES,
+        }
+    }
+}
+
+struct Rule {
+    pattern: &'static str,
+    left: Option<Regex>,
+    right: Option<Regex>,
+    phonetic: Vec<&'static str>,
+}
+
+impl Rule {
+    fn compile(rules: &[RuleDef]) -> Vec<Rule> {
+        rules
+            .iter()
+            .map(|(pattern, left, right, phonetic)| Rule {
+                pattern,
+                left: (!left.is_empty()).then(|| Regex::new(&format!("(?:{})$", left)).unwrap()),
+                right: (!right.is_empty()).then(|| Regex::new(&format!("^(?:{})", right)).unwrap()),
+                phonetic: phonetic.split('|').collect(),
+            })
+            .collect()
+    }
+
+    fn matches(&self, word: &str, index: usize) -> bool {
+        word[index..].starts_with(self.pattern)
+            && self
+                .left
+                .as_ref()
+                .is_none_or(|l| l.is_match(&word[..index]))
+            && self
+                .right
+                .as_ref()
+                .is_none_or(|r| r.is_match(&word[index + self.pattern.len()..]))
+    }
+}
+
+pub struct MultilingualPhonetic {
+    name_type: NameType,
+    name_type_rules: Vec<Rule>,
+    guesser: LanguageGuesser,
+    language_rules: Vec<(Language, Vec<Rule>)>,
+    common_rules: Vec<Rule>,
+    final_rules: Vec<Rule>,
+}
+
+impl MultilingualPhonetic {
+    pub fn new() -> Self {
+        MultilingualPhonetic {
+            name_type: NameType::Generic,
+            name_type_rules: Rule::compile(name_type_rules(NameType::Generic)),
+            guesser: LanguageGuesser::new(),
+            language_rules: Language::ALL
+                .into_iter()
+                .map(|language| (language, Rule::compile(language_rules(language))))
+                .collect(),
+            common_rules: Rule::compile(COMMON),
+            final_rules: Rule::compile(FINAL_APPROX),
+        }
+    }
+
+    pub fn with_name_type(mut self, name_type: NameType) -> Self {
+        self.name_type = name_type;
+        self.name_type_rules = Rule::compile(name_type_rules(name_type));
+        self
+    }
+
+    /// Guesses the languages `name` may come from, limited to those of the configured name type.
+    /// An empty result means the spelling rules out every language.
+    pub fn guess_languages(&self, name: &str) -> Vec<Language> {
+        let name = name.to_lowercase();
+        languages_from_bits(self.guesser.guess(&name, self.name_type.languages()))
+    }
+
+    /// Returns the sorted set of phonetic tokens for `val`.
+    pub fn encode(&self, val: &str) -> Result<Vec<String>, PhoneticError> {
+        validate_name(val)?;
+
+        let name = val.to_lowercase();
+        let mut tokens = BTreeSet::new();
+        for variant in self.name_variants(&name) {
+            let mut languages = self.guesser.guess(variant, self.name_type.languages());
+            if languages == 0 {
+                languages = self.name_type.languages();
+            }
+            tokens.extend(self.encode_word(variant, languages)?);
+        }
+
+        Ok(tokens.into_iter().collect())
+    }
+
+    /// Encodes `val` using the rules of the given languages instead of guessing them. With no
+    /// languages given, those of the name type are used.
+    pub fn encode_with_languages(
+        &self,
+        val: &str,
+        languages: &[Language],
+    ) -> Result<Vec<String>, PhoneticError> {
+        validate_name(val)?;
+
+        let name = val.to_lowercase();
+        let languages = match languages.iter().fold(0, |bits, l| bits | l.bit()) {
+            0 => self.name_type.languages(),
+            languages => languages,
+        };
+
+        let mut tokens = BTreeSet::new();
+        for variant in self.name_variants(&name) {
+            tokens.extend(self.encode_word(variant, languages)?);
+        }
+
+        Ok(tokens.into_iter().collect())
+    }
+
+    /// Two values match when they share at least one phonetic token.
+    pub fn compare(&self, val1: &str, val2: &str) -> Result<bool, PhoneticError> {
+        let tokens1 = self.encode(val1)?;
+        let tokens2 = self.encode(val2)?;
+
+        Ok(tokens1.iter().any(|token| tokens2.contains(token)))
+    }
+
+    /// Splits off leading name particles: generic names are encoded both with and without them,
+    /// while Ashkenazi and Sephardic names drop them.
+    fn name_variants<'a>(&self, name: &'a str) -> Vec<&'a str> {
+        let name = name.trim();
+        let mut rest = name;
+
+        while let Some(end) = rest.find(is_separator) {
+            if !self.name_type.prefixes().contains(&&rest[..end]) {
+                break;
+            }
+            rest = rest[end..].trim_start_matches(is_separator);
+        }
+
+        if rest.len() == name.len() || rest.is_empty() {
+            return vec![name];
+        }
+
+        match self.name_type {
+            NameType::Generic => vec![rest, name],
+            NameType::Ashkenazi | NameType::Sephardic => vec![rest],
+        }
+    }
+
+    fn encode_word(&self, word: &str, languages: u16) -> Result<BTreeSet<String>, PhoneticError> {
+        let word: String = word.chars().filter(|c| !is_separator(*c)).collect();
+        let mut tokens = BTreeSet::new();
+
+        for (language, rules) in &self.language_rules {
+            if languages & language.bit() == 0 {
+                continue;
+            }
+
+            for phonetic in apply_rules(&word, &[&self.name_type_rules, rules, &self.common_rules])?
+            {
+                for token in apply_rules(&phonetic, &[&self.final_rules])? {
+                    tokens.insert(collapse_repeats(&token));
+                }
+            }
+        }
+
+        Ok(tokens)
+    }
+}
+
+impl Default for MultilingualPhonetic {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PhoneticEncoder for MultilingualPhonetic {
+    /// Joins the phonetic tokens with '|'.
+    fn encode(&self, val: &str) -> Result<String, PhoneticError> {
+        Ok(MultilingualPhonetic::encode(self, val)?.join("|"))
+    }
+
+    fn compare(&self, val1: &str, val2: &str) -> Result<bool, PhoneticError> {
+        MultilingualPhonetic::compare(self, val1, val2)
+    }
+
+    fn name(&self) -> &'static str {
+        "multilingual"
+    }
+
+    fn max_code_length(&self) -> Option<usize> {
+        None
+    }
+
+    fn config(&self) -> String {
+        let name_type = match self.name_type {
+            NameType::Generic => "generic",
+            NameType::Ashkenazi => "ashkenazi",
+            NameType::Sephardic => "sephardic",
+        };
+        format!("name_type={}", name_type)
+    }
+}
+
+/// Rewrites `word` left to right, at each position applying the first matching rule from the
+/// first rule set that has one. Characters no rule matches are copied through unchanged.
+fn apply_rules(word: &str, rule_sets: &[&[Rule]]) -> Result<Vec<String>, PhoneticError> {
+    let mut outputs = vec![String::new()];
+    let mut index = 0;
+
+    while index < word.len() {
+        let rule = rule_sets
+            .iter()
+            .find_map(|rules| rules.iter().find(|rule| rule.matches(word, index)));
+
+        match rule {
+            Some(rule) => {
+                let mut next = Vec::with_capacity(outputs.len() * rule.phonetic.len());
+                let mut seen = HashSet::new();
+                for output in &outputs {
+                    for phonetic in &rule.phonetic {
+                        let candidate = format!("{}{}", output, phonetic);
+                        if seen.insert(candidate.clone()) {
+                            next.push(candidate);
+                        }
+                    }
+                }
+                if next.len() > MAX_BRANCHES {
+                    return Err(PhoneticError::TooManyReadings {
+                        limit: MAX_BRANCHES,
+                    });
+                }
+                outputs = next;
+                index += rule.pattern.len();
+            }
+            None => {
+                let c = word[index..].chars().next().unwrap();
+                for output in &mut outputs {
+                    output.push(c);
+                }
+                index += c.len_utf8();
+            }
+        }
+    }
+
+    Ok(outputs)
+}
+
+fn is_separator(c: char) -> bool {
+    c.is_whitespace() || c == '-' || c == '\''
+}
+
+fn collapse_repeats(token: &str) -> String {
+    let mut result = String::with_capacity(token.len());
+    let mut chars = token.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if chars.peek() != Some(&c) {
+            result.push(c);
+        }
+    }
+
+    result
+}
+
+/// Multi-word names are allowed, so spaces, hyphens and apostrophes pass validation.
+fn validate_name(val: &str) -> Result<(), PhoneticError> {
+    if val.trim().is_empty() {
+        return Err(PhoneticError::Empty);
+    }
+
+    for (position, c) in val.chars().enumerate() {
+        if is_separator(c) {
+            continue;
+        }
+        if !c.is_alphabetic() {
+            return Err(PhoneticError::NonAlphabetic { position, char: c });
+        }
+        if !is_latin(c) {
+            return Err(PhoneticError::UnsupportedScript { position, char: c });
+        }
+    }
+
+    Ok(())
+}
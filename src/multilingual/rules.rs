@@ -0,0 +1,390 @@
+use super::NameType;
+use super::lang::Language;
+
+// Phonetic rules are (pattern, left context, right context, phonetic). Contexts are regular
+// expressions matched against the text immediately before and after the pattern, and an empty
+// context matches anything. The phonetic value lists alternatives separated by '|'.
+//
+// The phonetic alphabet is lowercase Latin letters plus "S" (as in "shoe"), "Z" (as in
+// "measure") and "x" (as in "loch").
+pub(crate) type RuleDef = (&'static str, &'static str, &'static str, &'static str);
+
+const VOWEL: &str = "[aeiouy]";
+
+const ENGLISH: &[RuleDef] = &[
+    ("tch", "", "", "tS"),
+    ("ch", "", "", "tS|k"),
+    ("sh", "", "", "S"),
+    ("th", "", "", "t"),
+    ("gh", "", "$", ""),
+    ("gh", "[aeiou]", "t", ""),
+    ("gh", "", "", "g"),
+    ("kn", "^", "", "n"),
+    ("wr", "^", "", "r"),
+    ("wh", "^", "", "v"),
+    ("c", "", "[eiy]", "s"),
+    ("g", "", "[eiy]", "g|dZ"),
+    ("j", "", "", "dZ"),
+    ("qu", "", "", "kv"),
+    ("x", "^", "", "z"),
+    ("oo", "", "", "u"),
+    ("ee", "", "", "i"),
+    ("ea", "", "", "i"),
+    ("ou", "", "", "u|o"),
+    ("ow", "", "$", "o|au"),
+    ("ay", "", "", "ej"),
+    ("ey", "", "$", "i"),
+    ("ie", "", "$", "i"),
+    ("y", "^", VOWEL, "j"),
+    ("e", "[bcdfgklmnprstvz]", "$", ""),
+    ("w", "", VOWEL, "v"),
+    ("w", "", "", ""),
+];
+
+const FRENCH: &[RuleDef] = &[
+    ("eaux", "", "$", "o"),
+    ("eau", "", "", "o"),
+    ("ault", "", "$", "o"),
+    ("eux", "", "$", "e"),
+    ("au", "", "", "o"),
+    ("ou", "", "", "u"),
+    ("oi", "", "", "va"),
+    ("ch", "", "", "S"),
+    ("th", "", "", "t"),
+    ("gn", "", "", "nj"),
+    ("ill", "[aeiou]", "", "j"),
+    ("qu", "", "", "k"),
+    ("c", "", "[eiy]", "s"),
+    ("ge", "", "[aou]", "Z"),
+    ("g", "", "[eiy]", "Z"),
+    ("j", "", "", "Z"),
+    ("h", "", "", ""),
+    ("er", "", "$", "e|er"),
+    ("ez", "", "$", "e"),
+    ("x", "", "$", ""),
+    ("s", "", "$", ""),
+    ("t", "", "$", ""),
+    ("d", "", "$", ""),
+    ("z", "", "$", ""),
+    ("e", "", "$", ""),
+    ("s", "[aeiou]", "[aeiou]", "z"),
+    ("é", "", "", "e"),
+    ("è", "", "", "e"),
+    ("ê", "", "", "e"),
+    ("ë", "", "", "e"),
+];
+
+const GERMAN: &[RuleDef] = &[
+    ("tsch", "", "", "tS"),
+    ("sch", "", "", "S"),
+    ("sp", "^", "", "Sp"),
+    ("st", "^", "", "St"),
+    ("chs", "", "", "ks"),
+    ("ch", "", "", "x"),
+    ("tz", "", "", "ts"),
+    ("z", "", "", "ts"),
+    ("ei", "", "", "aj"),
+    ("ey", "", "", "aj"),
+    ("ai", "", "", "aj"),
+    ("eu", "", "", "oj"),
+    ("äu", "", "", "oj"),
+    ("ie", "", "", "i"),
+    ("ae", "", "", "e"),
+    ("oe", "", "", "e|o"),
+    ("ue", "", "", "i|u"),
+    ("ä", "", "", "e"),
+    ("ö", "", "", "e|o"),
+    ("ü", "", "", "i|u"),
+    ("ß", "", "", "s"),
+    ("s", "^", VOWEL, "z"),
+    ("s", "[aeiou]", "[aeiou]", "z"),
+    ("w", "", "", "v"),
+    ("v", "", "", "f|v"),
+    ("qu", "", "", "kv"),
+    ("th", "", "", "t"),
+    ("dt", "", "$", "t"),
+    ("h", "[aeiouäöü]", "", ""),
+    ("c", "", "[eiy]", "ts"),
+];
+
+const HUNGARIAN: &[RuleDef] = &[
+    ("cs", "", "", "tS"),
+    ("ch", "", "", "tS"),
+    ("cz", "", "", "ts"),
+    ("sz", "", "", "s"),
+    ("zs", "", "", "Z"),
+    ("s", "", "", "S"),
+    ("c", "", "", "ts"),
+    ("gy", "", "", "dj"),
+    ("ny", "", "", "nj"),
+    ("ty", "", "", "tj"),
+    ("ly", "", "", "j"),
+    ("y", "", "$", "i"),
+    ("ö", "", "", "e|o"),
+    ("ő", "", "", "e|o"),
+    ("ü", "", "", "i|u"),
+    ("ű", "", "", "i|u"),
+];
+
+const ITALIAN: &[RuleDef] = &[
+    ("cch", "", "", "k"),
+    ("ch", "", "", "k"),
+    ("cci", "", "[aou]", "tS"),
+    ("ci", "", "[aou]", "tS"),
+    ("cc", "", "[ei]", "tS"),
+    ("c", "", "[ei]", "tS"),
+    ("ggi", "", "[aou]", "dZ"),
+    ("gi", "", "[aou]", "dZ"),
+    ("gg", "", "[ei]", "dZ"),
+    ("g", "", "[ei]", "dZ"),
+    ("gh", "", "", "g"),
+    ("gli", "", "", "lj"),
+    ("gn", "", "", "nj"),
+    ("sci", "", "[aou]", "S"),
+    ("sc", "", "[ei]", "S"),
+    ("zz", "", "", "ts|dz"),
+    ("z", "", "", "ts|dz"),
+    ("h", "", "", ""),
+    ("qu", "", "", "kv"),
+];
+
+const POLISH: &[RuleDef] = &[
+    ("szcz", "", "", "StS"),
+    ("sz", "", "", "S"),
+    ("cz", "", "", "tS"),
+    ("rz", "", "", "Z|S"),
+    ("ch", "", "", "x"),
+    ("dż", "", "", "dZ"),
+    ("ż", "", "", "Z"),
+    ("ź", "", "", "Z"),
+    ("si", "", "[aeiou]", "S"),
+    ("ci", "", "[aeiou]", "tS"),
+    ("zi", "", "[aeiou]", "Z"),
+    ("ś", "", "", "S"),
+    ("ć", "", "", "tS"),
+    ("ł", "", "", "v|l"),
+    ("ó", "", "", "u"),
+    ("ą", "", "", "on|om"),
+    ("ę", "", "", "en|em|e"),
+    ("wicz", "", "$", "vitS"),
+    ("ow", "", "$", "of|ov"),
+    ("ew", "", "$", "ef|ev"),
+    ("w", "", "", "v"),
+    ("c", "", "", "ts"),
+    ("j", "", "", "j"),
+];
+
+const PORTUGUESE: &[RuleDef] = &[
+    ("ão", "", "", "aun|an"),
+    ("õe", "", "", "oin|on"),
+    ("nh", "", "", "nj"),
+    ("lh", "", "", "lj"),
+    ("ch", "", "", "S"),
+    ("ç", "", "", "s"),
+    ("c", "", "[ei]", "s"),
+    ("g", "", "[ei]", "Z"),
+    ("j", "", "", "Z"),
+    ("x", "", "", "S|ks"),
+    ("h", "", "", ""),
+    ("qu", "", "[ei]", "k"),
+    ("s", "[aeiou]", "[aeiou]", "z"),
+    ("z", "", "$", "s"),
+    ("o", "", "$", "u|o"),
+    ("e", "", "$", "i|e"),
+];
+
+const ROMANIAN: &[RuleDef] = &[
+    ("ș", "", "", "S"),
+    ("ş", "", "", "S"),
+    ("ț", "", "", "ts"),
+    ("ţ", "", "", "ts"),
+    ("ă", "", "", "e|a"),
+    ("â", "", "", "i|e"),
+    ("î", "", "", "i|e"),
+    ("ch", "", "[ei]", "k"),
+    ("ci", "", "[aeiou]", "tS"),
+    ("c", "", "[ei]", "tS"),
+    ("gh", "", "[ei]", "g"),
+    ("gi", "", "[aeiou]", "dZ"),
+    ("g", "", "[ei]", "dZ"),
+    ("j", "", "", "Z"),
+];
+
+const RUSSIAN: &[RuleDef] = &[
+    ("shch", "", "", "StS"),
+    ("sch", "", "", "S|StS"),
+    ("zh", "", "", "Z"),
+    ("kh", "", "", "x"),
+    ("tch", "", "", "tS"),
+    ("ch", "", "", "tS"),
+    ("sh", "", "", "S"),
+    ("tz", "", "", "ts"),
+    ("ya", "", "", "ja"),
+    ("yu", "", "", "ju"),
+    ("ye", "", "", "je"),
+    ("yo", "", "", "jo"),
+    ("iy", "", "$", "i"),
+    ("yi", "", "$", "i"),
+    ("ov", "", "$", "of|ov"),
+    ("ev", "", "$", "ef|ev"),
+    ("off", "", "$", "of"),
+    ("eff", "", "$", "ef"),
+    ("j", "", "", "j"),
+    ("c", "", "", "ts|k"),
+];
+
+const SPANISH: &[RuleDef] = &[
+    ("ch", "", "", "tS"),
+    ("ll", "", "", "j|l"),
+    ("ñ", "", "", "nj"),
+    ("qu", "", "[ei]", "k"),
+    ("gu", "", "[ei]", "g"),
+    ("c", "", "[ei]", "s"),
+    ("z", "", "", "s"),
+    ("j", "", "", "x"),
+    ("g", "", "[ei]", "x"),
+    ("h", "", "", ""),
+    ("v", "", "", "b"),
+    ("y", "", "$", "i"),
+    ("y", "", "", "j"),
+    ("x", "", "", "ks|x"),
+];
+
+/// Ashkenazi names, mostly Yiddish and Slavic names in German or Polish spelling, applied
+/// before the language-specific rules.
+const ASHKENAZI: &[RuleDef] = &[
+    ("tsch", "", "", "tS"),
+    ("sch", "", "", "S"),
+    ("sh", "", "", "S"),
+    ("zh", "", "", "Z"),
+    ("kh", "", "", "x"),
+    ("ch", "", "", "x|tS"),
+    ("cz", "", "", "tS"),
+    ("sz", "", "", "S"),
+    ("tz", "", "", "ts"),
+    ("ck", "", "", "k"),
+    ("ph", "", "", "f"),
+    ("ei", "", "", "aj"),
+    ("ey", "", "", "aj"),
+    ("ie", "", "", "i"),
+    ("j", "^", VOWEL, "j|dZ"),
+    ("w", "", "", "v"),
+    ("y", "", "[^aeiou]|$", "i"),
+    ("stein", "", "$", "Stajn|stajn|stin"),
+    ("berg", "", "$", "berk"),
+];
+
+/// Sephardic names, mostly in Spanish, Portuguese or Italian spelling, applied before the
+/// language-specific rules.
+const SEPHARDIC: &[RuleDef] = &[
+    ("sch", "", "", "S"),
+    ("sh", "", "", "S"),
+    ("ch", "", "", "tS|S"),
+    ("ll", "", "", "l|j"),
+    ("lh", "", "", "lj"),
+    ("nh", "", "", "nj"),
+    ("ny", "", "", "nj"),
+    ("ñ", "", "", "nj"),
+    ("qu", "", "[ei]", "k"),
+    ("qu", "", "", "kv"),
+    ("gu", "", "[ei]", "g"),
+    ("h", "", "", ""),
+    ("j", "", "", "x|Z"),
+    ("x", "", "", "S|x|ks"),
+    ("ç", "", "", "s"),
+    ("c", "", "[ei]", "s"),
+    ("z", "", "", "s|z"),
+    ("b", "", "", "b|v"),
+    ("v", "", "", "b|v"),
+    ("y", "", VOWEL, "j"),
+    ("y", "", "", "i"),
+];
+
+/// Applied after the language-specific rules to letters none of them claimed.
+pub(crate) const COMMON: &[RuleDef] = &[
+    ("á", "", "", "a"),
+    ("à", "", "", "a"),
+    ("â", "", "", "a"),
+    ("ä", "", "", "a"),
+    ("ã", "", "", "a"),
+    ("ą", "", "", "a"),
+    ("ă", "", "", "a"),
+    ("é", "", "", "e"),
+    ("è", "", "", "e"),
+    ("ê", "", "", "e"),
+    ("ë", "", "", "e"),
+    ("ę", "", "", "e"),
+    ("í", "", "", "i"),
+    ("ì", "", "", "i"),
+    ("î", "", "", "i"),
+    ("ï", "", "", "i"),
+    ("ó", "", "", "o"),
+    ("ò", "", "", "o"),
+    ("ô", "", "", "o"),
+    ("ö", "", "", "o"),
+    ("õ", "", "", "o"),
+    ("ő", "", "", "o"),
+    ("ú", "", "", "u"),
+    ("ù", "", "", "u"),
+    ("û", "", "", "u"),
+    ("ü", "", "", "u"),
+    ("ű", "", "", "u"),
+    ("ç", "", "", "s"),
+    ("ñ", "", "", "n"),
+    ("ń", "", "", "n"),
+    ("ß", "", "", "s"),
+    ("ł", "", "", "l"),
+    ("ś", "", "", "s"),
+    ("ź", "", "", "z"),
+    ("ż", "", "", "z"),
+    ("ć", "", "", "ts"),
+    ("ș", "", "", "S"),
+    ("ş", "", "", "S"),
+    ("ț", "", "", "ts"),
+    ("ţ", "", "", "ts"),
+    ("ph", "", "", "f"),
+    ("ck", "", "", "k"),
+    ("c", "", "", "k"),
+    ("q", "", "", "k"),
+    ("x", "", "", "ks"),
+    ("w", "", "", "v"),
+    ("y", "", "", "i"),
+];
+
+/// Approximate-matching rules applied to the phonetic output of every language.
+pub(crate) const FINAL_APPROX: &[RuleDef] = &[
+    ("h", "", "$", ""),
+    ("h", "[^aeiou]", "", ""),
+    ("dZ", "", "$", "tS"),
+    ("b", "", "$", "p"),
+    ("d", "", "$", "t"),
+    ("g", "", "$", "k"),
+    ("v", "", "$", "f"),
+    ("z", "", "$", "s"),
+    ("Z", "", "$", "S"),
+];
+
+/// Rules for the spelling conventions of a name type, which take precedence over the
+/// language-specific ones. Generic names use the language rules alone.
+pub(crate) fn name_type_rules(name_type: NameType) -> &'static [RuleDef] {
+    match name_type {
+        NameType::Generic => &[],
+        NameType::Ashkenazi => ASHKENAZI,
+        NameType::Sephardic => SEPHARDIC,
+    }
+}
+
+pub(crate) fn language_rules(language: Language) -> &'static [RuleDef] {
+    match language {
+        Language::English => ENGLISH,
+        Language::French => FRENCH,
+        Language::German => GERMAN,
+        Language::Hungarian => HUNGARIAN,
+        Language::Italian => ITALIAN,
+        Language::Polish => POLISH,
+        Language::Portuguese => PORTUGUESE,
+        Language::Romanian => ROMANIAN,
+        Language::Russian => RUSSIAN,
+        Language::Spanish => SPANISH,
+    }
+}
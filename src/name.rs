@@ -0,0 +1,198 @@
+use std::ops::Range;
+
+use crate::encoder::PhoneticEncoder;
+use crate::error::PhoneticError;
+use crate::particle::{Particle, ParticleHandling, ParticleNormalizer};
+
+/// How [`NameEncoder`] builds the code of a whole name from its tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TokenMode {
+    /// Each token is encoded on its own and the codes are joined with spaces.
+    #[default]
+    Separate,
+    /// The tokens are joined into one word, which is encoded as a whole.
+    Joined,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NameToken {
+    pub text: String,
+    /// Char offsets of the token within the name, after any particle normalization.
+    pub span: Range<usize>,
+    pub code: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncodedName {
+    pub tokens: Vec<NameToken>,
+    /// The code of the whole name, built as set by [`TokenMode`].
+    pub code: String,
+    /// The particles found by the encoder's [`ParticleNormalizer`], if it has one.
+    pub particles: Vec<Particle>,
+}
+
+/// Encodes full names such as "Mary Ann" or "O'Brien", which single-word encoders reject or
+/// run together, by splitting them on whitespace, hyphens and apostrophes.
+pub struct NameEncoder {
+    encoder: Box<dyn PhoneticEncoder>,
+    mode: TokenMode,
+    normalizer: Option<ParticleNormalizer>,
+}
+
+impl NameEncoder {
+    pub fn new(encoder: Box<dyn PhoneticEncoder>) -> Self {
+        NameEncoder {
+            encoder,
+            mode: TokenMode::default(),
+            normalizer: None,
+        }
+    }
+
+    pub fn with_mode(mut self, mode: TokenMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Normalizes surname particles before tokenizing.
+    pub fn with_normalizer(mut self, normalizer: ParticleNormalizer) -> Self {
+        self.normalizer = Some(normalizer);
+        self
+    }
+
+    /// Encodes every token of `name`. Error positions are char offsets within `name`, after
+    /// any particle normalization.
+    pub fn encode_name(&self, name: &str) -> Result<EncodedName, PhoneticError> {
+        let (name, particles) = match &self.normalizer {
+            Some(normalizer) => {
+                let normalized = normalizer.normalize(name);
+                (normalized.name, normalized.particles)
+            }
+            None => (name.to_string(), Vec::new()),
+        };
+        let spans = tokenize(&name);
+        if spans.is_empty() {
+            return Err(PhoneticError::Empty);
+        }
+
+        let chars: Vec<char> = name.chars().collect();
+        let mut tokens = Vec::with_capacity(spans.len());
+        for span in spans {
+            let text: String = chars[span.clone()].iter().collect();
+            let code = self
+                .encoder
+                .encode(&text)
+                .map_err(|err| relocate_error(err, |position| position + span.start))?;
+            tokens.push(NameToken { text, span, code });
+        }
+
+        let code = match self.mode {
+            TokenMode::Separate => tokens
+                .iter()
+                .map(|token| token.code.as_str())
+                .collect::<Vec<_>>()
+                .join(" "),
+            TokenMode::Joined => {
+                let joined: String = tokens.iter().map(|token| token.text.as_str()).collect();
+                self.encoder.encode(&joined).map_err(|err| {
+                    relocate_error(err, |position| joined_to_name(&tokens, position))
+                })?
+            }
+        };
+
+        Ok(EncodedName {
+            tokens,
+            code,
+            particles,
+        })
+    }
+}
+
+fn is_separator(c: char) -> bool {
+    c.is_whitespace() || matches!(c, '-' | '\u{2010}' | '\u{2011}' | '\'' | '\u{2019}')
+}
+
+/// Char ranges of the runs of non-separator characters in `name`.
+pub(crate) fn tokenize(name: &str) -> Vec<Range<usize>> {
+    let mut spans = Vec::new();
+    let mut start = None;
+
+    for (i, c) in name.chars().enumerate() {
+        match (is_separator(c), start) {
+            (true, Some(s)) => {
+                spans.push(s..i);
+                start = None;
+            }
+            (false, None) => start = Some(i),
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        spans.push(s..name.chars().count());
+    }
+
+    spans
+}
+
+/// Maps a char offset within the tokens joined together to the same char within the name.
+fn joined_to_name(tokens: &[NameToken], mut position: usize) -> usize {
+    for token in tokens {
+        if position < token.span.len() {
+            return token.span.start + position;
+        }
+        position -= token.span.len();
+    }
+    position + tokens.last().map_or(0, |token| token.span.end)
+}
+
+/// Moves the position of an error raised on part of the name to its position within the name.
+fn relocate_error(err: PhoneticError, relocate: impl Fn(usize) -> usize) -> PhoneticError {
+    match err {
+        PhoneticError::NonAlphabetic { position, char } => PhoneticError::NonAlphabetic {
+            position: relocate(position),
+            char,
+        },
+        PhoneticError::UnsupportedScript { position, char } => PhoneticError::UnsupportedScript {
+            position: relocate(position),
+            char,
+        },
+        PhoneticError::Empty | PhoneticError::TooManyReadings { .. } => err,
+    }
+}
+
+impl PhoneticEncoder for NameEncoder {
+    fn encode(&self, val: &str) -> Result<String, PhoneticError> {
+        Ok(self.encode_name(val)?.code)
+    }
+
+    fn name(&self) -> &'static str {
+        self.encoder.name()
+    }
+
+    fn max_code_length(&self) -> Option<usize> {
+        match self.mode {
+            TokenMode::Separate => None,
+            TokenMode::Joined => self.encoder.max_code_length(),
+        }
+    }
+
+    fn config(&self) -> String {
+        let mode = match self.mode {
+            TokenMode::Separate => "separate",
+            TokenMode::Joined => "joined",
+        };
+        let mut config = self.encoder.config();
+        if !config.is_empty() {
+            config.push(',');
+        }
+        config.push_str("tokens=");
+        config.push_str(mode);
+
+        match self.normalizer.as_ref().map(|n| n.handling()) {
+            Some(ParticleHandling::Canonicalize) => config.push_str(",particles=canonicalize"),
+            Some(ParticleHandling::Strip) => config.push_str(",particles=strip"),
+            None => {}
+        }
+
+        config
+    }
+}
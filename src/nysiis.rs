@@ -1,8 +1,74 @@
+use crate::encoder::{PhoneticEncoder, format_max_length};
+use crate::error::{PhoneticError, validate_encodable};
+use crate::explain::{ExplainStage, ExplainStep, Explanation, Tracer};
+use crate::transliterate::Transliterator;
 use dashmap::DashSet;
 use regex::Regex;
 
+const ORIGINAL_FIRST_CHARACTER_RULES: &[(&str, &str)] = &[
+    ("MAC", "MCC"),
+    ("KN", "NN"),
+    ("K", "C"),
+    ("PH", "FF"),
+    ("PF", "FF"),
+    ("SCH", "SSS"),
+];
+
+const EXTENDED_FIRST_CHARACTER_RULES: &[(&str, &str)] = &[
+    ("GB", "J"),
+    ("KP", "P"),
+    ("NW", "W"),
+    ("TS", "S"),
+    ("SH", "S"),
+    ("BH", "B"),
+    ("DH", "D"),
+    ("GH", "G"),
+    ("JH", "J"),
+    ("KH", "K"),
+    ("TH", "T"),
+    ("CH", "C"),
+    ("ZH", "J"),
+];
+
+const LAST_CHARACTER_RULES: &[(&str, &str)] = &[
+    ("EE", "Y"),
+    ("IE", "Y"),
+    ("DT", "D"),
+    ("RT", "D"),
+    ("RD", "D"),
+    ("NT", "D"),
+    ("ND", "D"),
+];
+
+const MODIFIED_FIRST_CHARACTER_RULES: &[(&str, &str)] = &[("MAC", "MC"), ("PF", "F")];
+
+const MODIFIED_LAST_CHARACTER_RULES: &[(&str, &str)] = &[
+    ("IX", "IC"),
+    ("EX", "EC"),
+    ("YE", "Y"),
+    ("EE", "Y"),
+    ("IE", "Y"),
+    ("NT", "D"),
+    ("ND", "D"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NysiisVariant {
+    /// NYSIIS as published by Taft (1970).
+    Original,
+    /// The modified NYSIIS of Lynch and Arends (1977), which adds rules such as GHT, DG and WR
+    /// and keeps a leading vowel.
+    Modified,
+    /// The original rules plus transcriptions for GB, KP, NW, TS and aspirated consonants, and
+    /// vowel harmony.
+    Extended,
+}
+
 pub struct Nysiis {
     vowels: DashSet<char>,
+    variant: NysiisVariant,
+    max_length: Option<usize>,
+    transliterator: Transliterator,
 }
 
 impl Nysiis {
@@ -14,25 +80,93 @@ impl Nysiis {
         vowels.insert('O');
         vowels.insert('U');
 
-        Nysiis { vowels }
+        Nysiis {
+            vowels,
+            variant: NysiisVariant::Extended,
+            max_length: Some(6),
+            transliterator: Transliterator::new(),
+        }
+    }
+
+    pub fn with_variant(mut self, variant: NysiisVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Sets the length keys are truncated to; `None` keeps the whole key.
+    pub fn with_max_length(mut self, max_length: Option<usize>) -> Self {
+        self.max_length = max_length;
+        self
+    }
+
+    /// Sets how accented and other non-ASCII letters are rewritten before encoding.
+    pub fn with_transliterator(mut self, transliterator: Transliterator) -> Self {
+        self.transliterator = transliterator;
+        self
     }
 
     pub fn encode(&self, name: &str) -> String {
+        self.encode_traced(name, &mut Tracer::disabled())
+    }
+
+    /// Encodes `name` and reports, in order, every rule that contributed to the key.
+    pub fn encode_explained(&self, name: &str) -> Explanation {
+        let mut tracer = Tracer::enabled();
+        let code = self.encode_traced(name, &mut tracer);
+
+        Explanation {
+            code,
+            steps: tracer.into_steps(),
+        }
+    }
+
+    /// Like [`Nysiis::encode`], but reports inputs that leave nothing to encode
+    /// instead of returning an empty key.
+    pub fn try_encode(&self, name: &str) -> Result<String, PhoneticError> {
+        validate_encodable(&self.transliterator.transliterate(name))?;
+
+        Ok(self.encode(name))
+    }
+
+    fn encode_traced(&self, name: &str, tracer: &mut Tracer) -> String {
         if name.is_empty() {
             return String::new();
         }
 
-        let name = self.preprocess_name(name);
+        let name = self.transliterator.transliterate_traced(name, tracer);
+        self.trace_preprocess(&name, tracer);
+        let name = self.preprocess_name(&name);
 
         if name.len() < 2 {
             return name;
         }
 
-        let name = self.translate_first_characters(&name);
-        let name = self.translate_last_characters(&name);
-        let key = self.generate_key(&name);
+        let key = match self.variant {
+            NysiisVariant::Original => {
+                let name = self.translate_first_characters(
+                    &name,
+                    &[ORIGINAL_FIRST_CHARACTER_RULES],
+                    tracer,
+                );
+                let name = self.translate_last_characters(&name, tracer);
+                self.generate_original_key(&name, tracer)
+            }
+            NysiisVariant::Modified => self.generate_modified_key(&name, tracer),
+            NysiisVariant::Extended => {
+                let name = self.translate_first_characters(
+                    &name,
+                    &[
+                        ORIGINAL_FIRST_CHARACTER_RULES,
+                        EXTENDED_FIRST_CHARACTER_RULES,
+                    ],
+                    tracer,
+                );
+                let name = self.translate_last_characters(&name, tracer);
+                self.generate_key(&name, tracer)
+            }
+        };
 
-        key
+        self.truncate_key(&key, tracer)
     }
 
     fn preprocess_name(&self, name: &str) -> String {
@@ -41,66 +175,72 @@ impl Nysiis {
         re.replace_all(&name, "").to_string()
     }
 
-    fn translate_first_characters(&self, name: &str) -> String {
-        if name.starts_with("MAC") {
-            return format!("MCC{}", &name[3..]);
-        } else if name.starts_with("KN") {
-            return format!("NN{}", &name[2..]);
-        } else if name.starts_with("K") {
-            return format!("C{}", &name[1..]);
-        } else if name.starts_with("PH") || name.starts_with("PF") {
-            return format!("FF{}", &name[2..]);
-        } else if name.starts_with("SCH") {
-            return format!("SSS{}", &name[3..]);
-        } else if name.starts_with("GB") {
-            return format!("J{}", &name[2..]);
-        } else if name.starts_with("KP") {
-            return format!("P{}", &name[2..]);
-        } else if name.starts_with("NW") {
-            return format!("W{}", &name[2..]);
-        } else if name.starts_with("TS") {
-            return format!("S{}", &name[2..]);
-        } else if name.starts_with("SH") {
-            return format!("S{}", &name[2..]);
-        } else if name.starts_with("BH") {
-            return format!("B{}", &name[2..]);
-        } else if name.starts_with("DH") {
-            return format!("D{}", &name[2..]);
-        } else if name.starts_with("GH") {
-            return format!("G{}", &name[2..]);
-        } else if name.starts_with("JH") {
-            return format!("J{}", &name[2..]);
-        } else if name.starts_with("KH") {
-            return format!("K{}", &name[2..]);
-        } else if name.starts_with("PH") {
-            return format!("F{}", &name[2..]);
-        } else if name.starts_with("TH") {
-            return format!("T{}", &name[2..]);
-        } else if name.starts_with("CH") {
-            return format!("C{}", &name[2..]);
-        } else if name.starts_with("ZH") {
-            return format!("J{}", &name[2..]);
+    /// Records each character [`Nysiis::preprocess_name`] drops.
+    fn trace_preprocess(&self, name: &str, tracer: &mut Tracer) {
+        for (i, c) in name.to_uppercase().chars().enumerate() {
+            if !c.is_ascii_uppercase() {
+                tracer.record(|| {
+                    ExplainStep::new(ExplainStage::Preprocess, "not A-Z", i..i + 1, c, "")
+                });
+            }
+        }
+    }
+
+    /// Applies the first matching prefix rule, trying the rule tables in order.
+    fn translate_first_characters(
+        &self,
+        name: &str,
+        rule_sets: &[&[(&'static str, &'static str)]],
+        tracer: &mut Tracer,
+    ) -> String {
+        for (prefix, replacement) in rule_sets.iter().flat_map(|rules| rules.iter()) {
+            if let Some(rest) = name.strip_prefix(prefix) {
+                tracer.record(|| {
+                    ExplainStep::new(
+                        ExplainStage::FirstCharacters,
+                        prefix,
+                        0..prefix.len(),
+                        *prefix,
+                        *replacement,
+                    )
+                });
+                return format!("{}{}", replacement, rest);
+            }
         }
 
         name.to_string()
     }
 
-    fn translate_last_characters(&self, name: &str) -> String {
-        if name.ends_with("EE") || name.ends_with("IE") {
-            return format!("{}Y", &name[..name.len() - 2]);
-        } else if name.ends_with("DT")
-            || name.ends_with("RT")
-            || name.ends_with("RD")
-            || name.ends_with("NT")
-            || name.ends_with("ND")
-        {
-            return format!("{}D", &name[..name.len() - 2]);
+    /// Applies the first matching suffix rule.
+    fn translate_suffix(
+        &self,
+        name: &str,
+        rules: &[(&'static str, &'static str)],
+        tracer: &mut Tracer,
+    ) -> String {
+        for (suffix, replacement) in rules {
+            if let Some(rest) = name.strip_suffix(suffix) {
+                tracer.record(|| {
+                    ExplainStep::new(
+                        ExplainStage::LastCharacters,
+                        suffix,
+                        rest.len()..name.len(),
+                        *suffix,
+                        *replacement,
+                    )
+                });
+                return format!("{}{}", rest, replacement);
+            }
         }
 
         name.to_string()
     }
 
-    fn generate_key(&self, name: &str) -> String {
+    fn translate_last_characters(&self, name: &str, tracer: &mut Tracer) -> String {
+        self.translate_suffix(name, LAST_CHARACTER_RULES, tracer)
+    }
+
+    fn generate_key(&self, name: &str, tracer: &mut Tracer) -> String {
         let name_chars: Vec<char> = name.chars().collect();
         let mut key = String::new();
         key.push(name_chars[0]);
@@ -114,39 +254,62 @@ impl Nysiis {
                 current_char = 'A';
             }
 
-            current_char = self.translate_char(current_char, &name_chars, i);
-            current_char = self.handle_vowel_harmony(current_char, prev_char);
+            let (translated, mut rule) = self.translate_char(current_char, &name_chars, i);
+            if rule.is_empty() {
+                rule = if current_char == 'A' {
+                    "vowel"
+                } else {
+                    "letter"
+                };
+            }
+            current_char = self.handle_vowel_harmony(translated, prev_char);
+            if current_char != translated {
+                rule = "vowel harmony";
+            }
             current_char = self.ignore_tonal_differences(current_char);
 
-            if current_char != prev_char {
+            let emitted = current_char != prev_char;
+            if emitted {
                 key.push(current_char);
             }
+            tracer.record(|| {
+                ExplainStep::new(
+                    ExplainStage::Transcode,
+                    rule,
+                    i..i + 1,
+                    name_chars[i],
+                    if emitted {
+                        current_char.to_string()
+                    } else {
+                        String::new()
+                    },
+                )
+            });
 
             prev_char = current_char;
         }
 
-        let key = self.remove_trailing_s(&key);
-        let key = self.translate_ay(&key);
-        let key = self.remove_trailing_a(&key);
-        let key = self.truncate_key(&key);
-
-        key
+        let key = self.remove_trailing_s(&key, tracer);
+        let key = self.translate_ay(&key, tracer);
+        self.remove_trailing_a(&key, tracer)
     }
 
-    fn translate_char(&self, c: char, name: &[char], i: usize) -> char {
+    /// Returns the translated character and the name of the rule that applied, or an empty
+    /// name when none did.
+    fn translate_char(&self, c: char, name: &[char], i: usize) -> (char, &'static str) {
         if c == 'E' && i + 1 < name.len() && name[i + 1] == 'V' {
-            return 'A';
+            return ('A', "EV");
         } else if c == 'Q' {
-            return 'G';
+            return ('G', "Q");
         } else if c == 'Z' {
-            return 'S';
+            return ('S', "Z");
         } else if c == 'M' {
-            return 'N';
+            return ('N', "M");
         } else if c == 'K' {
             if i + 1 < name.len() && name[i + 1] == 'N' {
-                return name[i];
+                return (name[i], "KN");
             } else {
-                return 'C';
+                return ('C', "K");
             }
         } else if c == 'S'
             && i + 2 < name.len()
@@ -154,103 +317,399 @@ impl Nysiis {
             && name[i + 1] == 'C'
             && name[i + 2] == 'H'
         {
-            return 'S';
+            return ('S', "SCH");
         } else if c == 'P' && i + 1 < name.len() && name[i + 1] == 'H' {
-            return 'F';
+            return ('F', "PH");
         } else if c == 'H'
             && (i == 0
                 || i + 1 == name.len()
                 || !self.vowels.contains(&name[i - 1])
                 || !self.vowels.contains(&name[i + 1]))
+            || (c == 'W' && i > 0 && self.vowels.contains(&name[i - 1]))
         {
-            return name[i - 1];
-        } else if c == 'W' && i > 0 && self.vowels.contains(&name[i - 1]) {
-            return name[i - 1];
+            let rule = if c == 'H' {
+                "H not between vowels"
+            } else {
+                "W after vowel"
+            };
+            return (name[i - 1], rule);
         } else if c == 'G' && i + 1 < name.len() && name[i + 1] == 'B' {
-            return 'J';
+            return ('J', "GB");
         } else if c == 'K' && i + 1 < name.len() && name[i + 1] == 'P' {
-            return 'P';
+            return ('P', "KP");
         } else if c == 'N' && i + 1 < name.len() && name[i + 1] == 'W' {
-            return 'W';
+            return ('W', "NW");
         } else if c == 'T' && i + 1 < name.len() && name[i + 1] == 'S' {
-            return 'S';
+            return ('S', "TS");
         } else if c == 'S' && i + 1 < name.len() && name[i + 1] == 'H' {
-            return 'S';
+            return ('S', "SH");
         } else if c == 'B' && i + 1 < name.len() && name[i + 1] == 'H' {
-            return 'B';
+            return ('B', "BH");
         } else if c == 'D' && i + 1 < name.len() && name[i + 1] == 'H' {
-            return 'D';
+            return ('D', "DH");
         } else if c == 'G' && i + 1 < name.len() && name[i + 1] == 'H' {
-            return 'G';
+            return ('G', "GH");
         } else if c == 'J' && i + 1 < name.len() && name[i + 1] == 'H' {
-            return 'J';
+            return ('J', "JH");
         } else if c == 'K' && i + 1 < name.len() && name[i + 1] == 'H' {
-            return 'K';
+            return ('K', "KH");
         } else if c == 'P' && i + 1 < name.len() && name[i + 1] == 'H' {
-            return 'F';
+            return ('F', "PH");
         } else if c == 'T' && i + 1 < name.len() && name[i + 1] == 'H' {
-            return 'T';
+            return ('T', "TH");
         } else if c == 'C' && i + 1 < name.len() && name[i + 1] == 'H' {
-            return 'C';
+            return ('C', "CH");
         } else if c == 'Z' && i + 1 < name.len() && name[i + 1] == 'H' {
-            return 'J';
+            return ('J', "ZH");
         }
 
-        c
+        (c, "")
     }
-
     fn handle_vowel_harmony(&self, c: char, prev: char) -> char {
         if self.vowels.contains(&c) && self.vowels.contains(&prev) {
             if prev == 'A' || prev == 'O' || prev == 'U' {
                 if c == 'E' || c == 'I' {
                     return 'A';
                 }
-            } else if prev == 'E' || prev == 'I' {
-                if c == 'A' || c == 'O' || c == 'U' {
-                    return 'E';
-                }
+            } else if (prev == 'E' || prev == 'I') && (c == 'A' || c == 'O' || c == 'U') {
+                return 'E';
             }
         }
         c
     }
 
     fn ignore_tonal_differences(&self, c: char) -> char {
-        if c >= 'A' && c <= 'Z' {
+        if c.is_ascii_uppercase() {
             c.to_ascii_uppercase()
         } else {
             c
         }
     }
 
-    fn remove_trailing_s(&self, key: &str) -> String {
+    fn remove_trailing_s(&self, key: &str, tracer: &mut Tracer) -> String {
         if key.len() > 1 && key.ends_with('S') {
+            self.trace_trailing_removal(key, "trailing S", tracer);
             key[..key.len() - 1].to_string()
         } else {
             key.to_string()
         }
     }
 
-    fn translate_ay(&self, key: &str) -> String {
-        if key.ends_with("AY") {
-            format!("{}Y", &key[..key.len() - 2])
+    fn translate_ay(&self, key: &str, tracer: &mut Tracer) -> String {
+        if let Some(rest) = key.strip_suffix("AY") {
+            tracer.record(|| {
+                ExplainStep::new(
+                    ExplainStage::TrailingSuffix,
+                    "trailing AY",
+                    rest.len()..rest.len() + 1,
+                    "A",
+                    "",
+                )
+            });
+            format!("{}Y", rest)
         } else {
             key.to_string()
         }
     }
 
-    fn remove_trailing_a(&self, key: &str) -> String {
+    fn remove_trailing_a(&self, key: &str, tracer: &mut Tracer) -> String {
         if key.len() > 1 && key.ends_with('A') {
+            self.trace_trailing_removal(key, "trailing A", tracer);
             key[..key.len() - 1].to_string()
         } else {
             key.to_string()
         }
     }
 
-    fn truncate_key(&self, key: &str) -> String {
-        if key.len() > 6 {
-            key[..6].to_string()
+    fn trace_trailing_removal(&self, key: &str, rule: &'static str, tracer: &mut Tracer) {
+        tracer.record(|| {
+            ExplainStep::new(
+                ExplainStage::TrailingSuffix,
+                rule,
+                key.len() - 1..key.len(),
+                &key[key.len() - 1..],
+                "",
+            )
+        });
+    }
+
+    /// Key generation following Taft's rules, where a transcription may also rewrite the
+    /// characters after the current one.
+    fn generate_original_key(&self, name: &str, tracer: &mut Tracer) -> String {
+        let mut chars: Vec<char> = name.chars().collect();
+        let mut key = String::new();
+        key.push(chars[0]);
+
+        for i in 1..chars.len() {
+            let next = chars.get(i + 1).copied().unwrap_or(' ');
+            let after_next = chars.get(i + 2).copied().unwrap_or(' ');
+            let (rule, transcoded) =
+                self.transcode_original(chars[i - 1], chars[i], next, after_next);
+            let span = i..i + transcoded.len();
+            let input: String = chars[span.clone()].iter().collect();
+            chars[span.clone()].copy_from_slice(&transcoded);
+
+            let emitted = chars[i] != chars[i - 1];
+            if emitted {
+                key.push(chars[i]);
+            }
+            tracer.record(|| {
+                ExplainStep::new(
+                    ExplainStage::Transcode,
+                    rule,
+                    span,
+                    input,
+                    if emitted {
+                        chars[i].to_string()
+                    } else {
+                        String::new()
+                    },
+                )
+            });
+        }
+
+        let key = self.remove_trailing_s(&key, tracer);
+        let key = self.translate_ay(&key, tracer);
+        self.remove_trailing_a(&key, tracer)
+    }
+
+    fn transcode_original(
+        &self,
+        prev: char,
+        c: char,
+        next: char,
+        after_next: char,
+    ) -> (&'static str, Vec<char>) {
+        match c {
+            'E' if next == 'V' => ("EV", vec!['A', 'F']),
+            c if self.vowels.contains(&c) => ("vowel", vec!['A']),
+            'Q' => ("Q", vec!['G']),
+            'Z' => ("Z", vec!['S']),
+            'M' => ("M", vec!['N']),
+            'K' if next == 'N' => ("KN", vec!['N', 'N']),
+            'K' => ("K", vec!['C']),
+            'S' if next == 'C' && after_next == 'H' => ("SCH", vec!['S', 'S', 'S']),
+            'P' if next == 'H' => ("PH", vec!['F', 'F']),
+            'H' if !self.vowels.contains(&prev) || !self.vowels.contains(&next) => {
+                ("H not between vowels", vec![prev])
+            }
+            'W' if self.vowels.contains(&prev) => ("W after vowel", vec![prev]),
+            c => ("letter", vec![c]),
+        }
+    }
+
+    /// Key generation for modified NYSIIS. Each rule is applied in turn to everything after
+    /// the first character, which is kept as written. Spans are offsets into the whole name
+    /// as it stood when the rule ran.
+    fn generate_modified_key(&self, name: &str, tracer: &mut Tracer) -> String {
+        let trimmed = match name.trim_end_matches(['S', 'Z']) {
+            "" => &name[..1],
+            trimmed => trimmed,
+        };
+        if trimmed.len() < name.len() {
+            tracer.record(|| {
+                ExplainStep::new(
+                    ExplainStage::LastCharacters,
+                    "trailing S/Z",
+                    trimmed.len()..name.len(),
+                    &name[trimmed.len()..],
+                    "",
+                )
+            });
+        }
+        let name =
+            self.translate_first_characters(trimmed, &[MODIFIED_FIRST_CHARACTER_RULES], tracer);
+        let name = self.translate_suffix(&name, MODIFIED_LAST_CHARACTER_RULES, tracer);
+
+        let first = name.chars().next().unwrap();
+        let rest = self.rewrite(first, &name[1..], "EV", "EF", tracer);
+        let rest = self.rewrite_in_context(first, &rest, "W after vowel", tracer, |prev, c, _| {
+            (c == 'W' && self.vowels.contains(&prev)).then_some("")
+        });
+        let rest = self.rewrite_in_context(first, &rest, "vowel", tracer, |_, c, _| {
+            (c != 'A' && self.vowels.contains(&c)).then_some("A")
+        });
+        let rest = self.rewrite(first, &rest, "GHT", "GT", tracer);
+        let rest = self.rewrite(first, &rest, "DG", "G", tracer);
+        let rest = self.rewrite(first, &rest, "PH", "F", tracer);
+        let rest =
+            self.rewrite_in_context(first, &rest, "H next to vowel", tracer, |prev, c, next| {
+                (c == 'H'
+                    && (self.vowels.contains(&prev)
+                        || next.is_some_and(|n| self.vowels.contains(&n))))
+                .then_some("")
+            });
+        let rest = self.rewrite(first, &rest, "KN", "N", tracer);
+        let rest = self.rewrite(first, &rest, "K", "C", tracer);
+        let rest = self.rewrite(first, &rest, "M", "N", tracer);
+        let rest = self.rewrite(first, &rest, "Q", "G", tracer);
+        let rest = self.rewrite(first, &rest, "SH", "S", tracer);
+        let rest = self.rewrite(first, &rest, "SCH", "S", tracer);
+        let rest = self.rewrite(first, &rest, "YW", "Y", tracer);
+        let rest = self.rewrite_in_context(first, &rest, "Y not last", tracer, |_, c, next| {
+            (c == 'Y' && next.is_some()).then_some("A")
+        });
+        let rest = self.rewrite(first, &rest, "WR", "R", tracer);
+        let rest = self.rewrite(first, &rest, "Z", "S", tracer);
+
+        let key = self.translate_ay(&format!("{}{}", first, rest), tracer);
+        let trimmed = key.trim_end_matches(|c| self.vowels.contains(&c));
+        let trimmed = if trimmed.is_empty() {
+            &key[..1]
         } else {
-            key.to_string()
+            trimmed
+        };
+        for i in trimmed.len()..key.len() {
+            tracer.record(|| {
+                ExplainStep::new(
+                    ExplainStage::TrailingSuffix,
+                    "trailing vowel",
+                    i..i + 1,
+                    &key[i..i + 1],
+                    "",
+                )
+            });
+        }
+
+        let mut collapsed = String::with_capacity(trimmed.len());
+        for (i, c) in trimmed.char_indices() {
+            if collapsed.ends_with(c) {
+                tracer.record(|| {
+                    ExplainStep::new(ExplainStage::Transcode, "repeated letter", i..i + 1, c, "")
+                });
+            } else {
+                collapsed.push(c);
+            }
+        }
+
+        collapsed
+    }
+
+    /// Replaces every occurrence of `pattern` in `rest`, the part of the name after `first`.
+    fn rewrite(
+        &self,
+        first: char,
+        rest: &str,
+        pattern: &'static str,
+        replacement: &'static str,
+        tracer: &mut Tracer,
+    ) -> String {
+        let mut result = String::with_capacity(rest.len());
+        let mut last_end = 0;
+
+        for (start, _) in rest.match_indices(pattern) {
+            result.push_str(&rest[last_end..start]);
+            let offset = first.len_utf8() + result.len();
+            tracer.record(|| {
+                ExplainStep::new(
+                    ExplainStage::Transcode,
+                    pattern,
+                    offset..offset + pattern.len(),
+                    pattern,
+                    replacement,
+                )
+            });
+            result.push_str(replacement);
+            last_end = start + pattern.len();
         }
+        result.push_str(&rest[last_end..]);
+
+        result
+    }
+
+    /// Replaces each character of `rest` for which `replace(prev, c, next)` returns a
+    /// replacement, where `prev` may be `first` and the neighbours are taken from the
+    /// unmodified text.
+    fn rewrite_in_context(
+        &self,
+        first: char,
+        rest: &str,
+        rule: &'static str,
+        tracer: &mut Tracer,
+        replace: impl Fn(char, char, Option<char>) -> Option<&'static str>,
+    ) -> String {
+        let chars: Vec<char> = rest.chars().collect();
+        let mut result = String::with_capacity(rest.len());
+
+        for i in 0..chars.len() {
+            let prev = if i == 0 { first } else { chars[i - 1] };
+            match replace(prev, chars[i], chars.get(i + 1).copied()) {
+                Some(replacement) => {
+                    let offset = first.len_utf8() + result.len();
+                    tracer.record(|| {
+                        ExplainStep::new(
+                            ExplainStage::Transcode,
+                            rule,
+                            offset..offset + 1,
+                            chars[i],
+                            replacement,
+                        )
+                    });
+                    result.push_str(replacement);
+                }
+                None => result.push(chars[i]),
+            }
+        }
+
+        result
+    }
+
+    fn truncate_key(&self, key: &str, tracer: &mut Tracer) -> String {
+        match self.max_length {
+            Some(max_length) if key.len() > max_length => {
+                tracer.record(|| {
+                    ExplainStep::new(
+                        ExplainStage::Truncate,
+                        "max length",
+                        max_length..key.len(),
+                        &key[max_length..],
+                        "",
+                    )
+                });
+                key[..max_length].to_string()
+            }
+            _ => key.to_string(),
+        }
+    }
+}
+
+impl Default for Nysiis {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PhoneticEncoder for Nysiis {
+    fn encode(&self, val: &str) -> Result<String, PhoneticError> {
+        self.try_encode(val)
+    }
+
+    fn name(&self) -> &'static str {
+        "nysiis"
+    }
+
+    fn max_code_length(&self) -> Option<usize> {
+        self.max_length
+    }
+
+    fn config(&self) -> String {
+        let variant = match self.variant {
+            NysiisVariant::Original => "original",
+            NysiisVariant::Modified => "modified",
+            NysiisVariant::Extended => "extended",
+        };
+        let mut config = format!(
+            "variant={},max_length={}",
+            variant,
+            format_max_length(self.max_length)
+        );
+
+        let transliteration = self.transliterator.config();
+        if !transliteration.is_empty() {
+            config.push(',');
+            config.push_str(&transliteration);
+        }
+
+        config
     }
 }
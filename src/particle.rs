@@ -0,0 +1,170 @@
+use std::ops::Range;
+
+use crate::name::tokenize;
+
+/// Particles recognized as separate words by default, in lowercase.
+const PARTICLES: &[&str] = &[
+    "al", "da", "das", "de", "del", "della", "den", "der", "di", "dos", "du", "el", "fitz", "ibn",
+    "la", "le", "mac", "mc", "o", "ter", "van", "von",
+];
+
+/// What [`ParticleNormalizer`] does with the particles it finds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParticleHandling {
+    /// Rewrites particles to one spelling and attaches them to the following word, so
+    /// "Mc Donald" becomes "MacDonald" and "van der Berg" becomes "vanderBerg".
+    #[default]
+    Canonicalize,
+    /// Drops particles, so "McDonald" becomes "Donald".
+    Strip,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Particle {
+    /// The particle as written.
+    pub text: String,
+    /// Char offsets of the particle within the original name.
+    pub span: Range<usize>,
+    /// What the particle was rewritten to, or `None` if it was stripped.
+    pub replacement: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NormalizedName {
+    /// The words of the name, separated by single spaces.
+    pub name: String,
+    pub particles: Vec<Particle>,
+}
+
+/// Recognizes surname particles and prefixes such as "Mc", "O'", "Van der", "De la", "Al-" and
+/// "Fitz", so that spellings like "McDonald", "MacDonald" and "Mac Donald" encode alike.
+///
+/// A separate particle is only recognized before another word, and an attached "Mac" only
+/// before a capital, as in "MacDonald" but not "Macy".
+pub struct ParticleNormalizer {
+    handling: ParticleHandling,
+    particles: Vec<String>,
+}
+
+impl ParticleNormalizer {
+    pub fn new() -> Self {
+        ParticleNormalizer {
+            handling: ParticleHandling::default(),
+            particles: PARTICLES.iter().map(|p| p.to_string()).collect(),
+        }
+    }
+
+    pub fn with_handling(mut self, handling: ParticleHandling) -> Self {
+        self.handling = handling;
+        self
+    }
+
+    /// Replaces the words recognized as separate particles; matching ignores case.
+    pub fn with_particles<I, S>(mut self, particles: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.particles = particles
+            .into_iter()
+            .map(|p| p.into().to_lowercase())
+            .collect();
+        self
+    }
+
+    pub fn handling(&self) -> ParticleHandling {
+        self.handling
+    }
+
+    pub fn normalize(&self, name: &str) -> NormalizedName {
+        let chars: Vec<char> = name.chars().collect();
+        let spans = tokenize(name);
+
+        let mut words = Vec::with_capacity(spans.len());
+        let mut particles = Vec::new();
+        // Canonicalized particles waiting to be attached to the next word.
+        let mut pending = String::new();
+
+        for (i, span) in spans.iter().enumerate() {
+            let word: String = chars[span.clone()].iter().collect();
+            let is_last = i + 1 == spans.len();
+
+            if !is_last && self.particles.contains(&word.to_lowercase()) {
+                let replacement = self.replacement(&word);
+                if let Some(replacement) = &replacement {
+                    pending.push_str(replacement);
+                }
+                particles.push(Particle {
+                    text: word,
+                    span: span.clone(),
+                    replacement,
+                });
+                continue;
+            }
+
+            let rest = match attached_prefix(&word) {
+                Some(length) => {
+                    let text: String = word.chars().take(length).collect();
+                    let replacement = self.replacement(&text);
+                    if let Some(replacement) = &replacement {
+                        pending.push_str(replacement);
+                    }
+                    particles.push(Particle {
+                        text,
+                        span: span.start..span.start + length,
+                        replacement,
+                    });
+                    word.chars().skip(length).collect()
+                }
+                None => word,
+            };
+
+            pending.push_str(&rest);
+            words.push(std::mem::take(&mut pending));
+        }
+
+        NormalizedName {
+            name: words.join(" "),
+            particles,
+        }
+    }
+
+    /// The canonical spelling of a particle, keeping the case of its first letter.
+    fn replacement(&self, particle: &str) -> Option<String> {
+        match self.handling {
+            ParticleHandling::Strip => None,
+            ParticleHandling::Canonicalize if particle.eq_ignore_ascii_case("mc") => {
+                let upper = particle.starts_with(char::is_uppercase);
+                Some(if upper { "Mac" } else { "mac" }.to_string())
+            }
+            ParticleHandling::Canonicalize => Some(particle.to_string()),
+        }
+    }
+}
+
+impl Default for ParticleNormalizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Length in chars of a "Mc", "Mac" or "Fitz" prefix written as part of `word`. "Mac" is
+/// only a prefix when written "Mac" before a capital, as in "MacDonald", since "MACK" and
+/// "MACY" in capitals look the same as "MACDONALD".
+fn attached_prefix(word: &str) -> Option<usize> {
+    let lower = word.to_lowercase();
+    let length = word.chars().count();
+
+    if lower.starts_with("mc") && length >= 4 {
+        Some(2)
+    } else if lower.starts_with("mac")
+        && word.get(1..3) == Some("ac")
+        && word.chars().nth(3).is_some_and(char::is_uppercase)
+    {
+        Some(3)
+    } else if lower.starts_with("fitz") && length >= 7 {
+        Some(4)
+    } else {
+        None
+    }
+}
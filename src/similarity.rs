@@ -0,0 +1,127 @@
+//! Graded similarity of names, computed over their phonetic codes.
+
+use crate::distance;
+use crate::encoder::PhoneticEncoder;
+use crate::error::PhoneticError;
+use crate::{Metaphone, Nysiis};
+
+/// How two codes are scored, always in `[0, 1]` with 1 for identical codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SimilarityMeasure {
+    /// One minus the Levenshtein distance over the length of the longer code.
+    EditDistance,
+    /// Jaro–Winkler similarity, which favours codes sharing their first characters.
+    #[default]
+    JaroWinkler,
+    /// Length of the common prefix over the length of the longer code.
+    CommonPrefix,
+}
+
+impl SimilarityMeasure {
+    /// Scores two codes. Codes listing alternatives separated by '|' score as their most
+    /// similar pair of alternatives.
+    pub fn score(&self, code1: &str, code2: &str) -> f64 {
+        let mut best: f64 = 0.0;
+
+        for alternative1 in code1.split('|') {
+            for alternative2 in code2.split('|') {
+                let score = match self {
+                    SimilarityMeasure::EditDistance => {
+                        distance::normalized_levenshtein(alternative1, alternative2)
+                    }
+                    SimilarityMeasure::JaroWinkler => {
+                        distance::jaro_winkler(alternative1, alternative2)
+                    }
+                    SimilarityMeasure::CommonPrefix => {
+                        prefix_similarity(alternative1, alternative2)
+                    }
+                };
+                best = best.max(score);
+            }
+        }
+
+        best
+    }
+}
+
+fn prefix_similarity(a: &str, b: &str) -> f64 {
+    let longest = a.chars().count().max(b.chars().count());
+    if longest == 0 {
+        return 1.0;
+    }
+
+    let prefix = a
+        .chars()
+        .zip(b.chars())
+        .take_while(|(ca, cb)| ca == cb)
+        .count();
+    prefix as f64 / longest as f64
+}
+
+/// Scores names across several encoders, as the weighted mean of their code similarities.
+pub struct PhoneticSimilarity {
+    encoders: Vec<(Box<dyn PhoneticEncoder>, f64)>,
+    measure: SimilarityMeasure,
+}
+
+impl PhoneticSimilarity {
+    /// Scores with [`Metaphone`] and [`Nysiis`] codes, weighted equally, using Jaro–Winkler.
+    pub fn new() -> Self {
+        Self::with_encoders(vec![Box::new(Metaphone::new()), Box::new(Nysiis::new())])
+    }
+
+    /// Uses `encoders`, weighted equally.
+    pub fn with_encoders(encoders: Vec<Box<dyn PhoneticEncoder>>) -> Self {
+        Self::with_weighted_encoders(encoders.into_iter().map(|e| (e, 1.0)).collect())
+    }
+
+    /// Uses each encoder with its weight; non-positive weights leave an encoder out.
+    pub fn with_weighted_encoders(encoders: Vec<(Box<dyn PhoneticEncoder>, f64)>) -> Self {
+        PhoneticSimilarity {
+            encoders: encoders.into_iter().filter(|(_, w)| *w > 0.0).collect(),
+            measure: SimilarityMeasure::default(),
+        }
+    }
+
+    pub fn with_measure(mut self, measure: SimilarityMeasure) -> Self {
+        self.measure = measure;
+        self
+    }
+
+    /// Returns a score in `[0, 1]`, 1 when every encoder gives both names the same code.
+    pub fn score(&self, val1: &str, val2: &str) -> Result<f64, PhoneticError> {
+        let mut total = 0.0;
+        let mut weights = 0.0;
+
+        for (encoder, weight) in &self.encoders {
+            let code1 = encoder.encode(val1)?;
+            let code2 = encoder.encode(val2)?;
+            total += weight * self.measure.score(&code1, &code2);
+            weights += weight;
+        }
+
+        Ok(if weights > 0.0 { total / weights } else { 0.0 })
+    }
+
+    /// Scores every candidate against `name`, most similar first; ties keep the candidates'
+    /// order.
+    pub fn rank<'a>(
+        &self,
+        name: &str,
+        candidates: &[&'a str],
+    ) -> Result<Vec<(&'a str, f64)>, PhoneticError> {
+        let mut ranked = candidates
+            .iter()
+            .map(|&candidate| Ok((candidate, self.score(name, candidate)?)))
+            .collect::<Result<Vec<_>, PhoneticError>>()?;
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        Ok(ranked)
+    }
+}
+
+impl Default for PhoneticSimilarity {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,178 @@
+use crate::encoder::{PhoneticEncoder, format_max_length};
+use crate::error::{PhoneticError, validate_alphabetic};
+use crate::transliterate::ascii_letters;
+
+//                           ABCDEFGHIJKLMNOPQRSTUVWXYZ
+const SOUNDEX_MAPPING: &[u8] = b"01230120022455012623010202";
+const REFINED_SOUNDEX_MAPPING: &[u8] = b"01360240043788015936020505";
+
+pub struct Soundex {
+    length: usize,
+    padding: bool,
+}
+
+impl Soundex {
+    pub fn new() -> Self {
+        Soundex {
+            length: 4,
+            padding: true,
+        }
+    }
+
+    pub fn with_length(mut self, length: usize) -> Self {
+        self.length = length;
+        self
+    }
+
+    /// Disables padding short codes with trailing zeros, so "Lee" encodes as "L" rather than "L000".
+    pub fn with_padding(mut self, padding: bool) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    pub fn encode(&self, val: &str) -> Result<String, PhoneticError> {
+        validate_alphabetic(val)?;
+
+        let letters = ascii_letters(val)?;
+        let first = letters[0];
+
+        let mut code = String::with_capacity(self.length);
+        code.push(first);
+        let mut last = soundex_code(first);
+
+        for &c in &letters[1..] {
+            if code.len() >= self.length {
+                break;
+            }
+
+            let digit = soundex_code(c);
+            match c {
+                // H and W do not separate letters with the same code.
+                'H' | 'W' => continue,
+                _ if digit == '0' => {}
+                _ if digit != last => code.push(digit),
+                _ => {}
+            }
+            last = digit;
+        }
+
+        if self.padding {
+            while code.len() < self.length {
+                code.push('0');
+            }
+        }
+        code.truncate(self.length);
+
+        Ok(code)
+    }
+
+    pub fn compare(&self, val1: &str, val2: &str) -> Result<bool, PhoneticError> {
+        let code1 = self.encode(val1)?;
+        let code2 = self.encode(val2)?;
+
+        Ok(code1 == code2)
+    }
+}
+
+impl Default for Soundex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PhoneticEncoder for Soundex {
+    fn encode(&self, val: &str) -> Result<String, PhoneticError> {
+        Soundex::encode(self, val)
+    }
+
+    fn name(&self) -> &'static str {
+        "soundex"
+    }
+
+    fn max_code_length(&self) -> Option<usize> {
+        Some(self.length)
+    }
+
+    fn config(&self) -> String {
+        format!("length={},padding={}", self.length, self.padding)
+    }
+}
+
+pub struct RefinedSoundex {
+    max_length: Option<usize>,
+}
+
+impl RefinedSoundex {
+    pub fn new() -> Self {
+        RefinedSoundex { max_length: None }
+    }
+
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    pub fn encode(&self, val: &str) -> Result<String, PhoneticError> {
+        validate_alphabetic(val)?;
+
+        let letters = ascii_letters(val)?;
+        let first = letters[0];
+
+        let mut code = String::new();
+        code.push(first);
+        let mut last = None;
+
+        for &c in &letters {
+            let digit = refined_soundex_code(c);
+            if last != Some(digit) {
+                code.push(digit);
+            }
+            last = Some(digit);
+        }
+
+        if let Some(max_length) = self.max_length {
+            code.truncate(max_length);
+        }
+
+        Ok(code)
+    }
+
+    pub fn compare(&self, val1: &str, val2: &str) -> Result<bool, PhoneticError> {
+        let code1 = self.encode(val1)?;
+        let code2 = self.encode(val2)?;
+
+        Ok(code1 == code2)
+    }
+}
+
+impl Default for RefinedSoundex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PhoneticEncoder for RefinedSoundex {
+    fn encode(&self, val: &str) -> Result<String, PhoneticError> {
+        RefinedSoundex::encode(self, val)
+    }
+
+    fn name(&self) -> &'static str {
+        "refined_soundex"
+    }
+
+    fn max_code_length(&self) -> Option<usize> {
+        self.max_length
+    }
+
+    fn config(&self) -> String {
+        format!("max_length={}", format_max_length(self.max_length))
+    }
+}
+
+fn soundex_code(c: char) -> char {
+    SOUNDEX_MAPPING[(c as u8 - b'A') as usize] as char
+}
+
+fn refined_soundex_code(c: char) -> char {
+    REFINED_SOUNDEX_MAPPING[(c as u8 - b'A') as usize] as char
+}
@@ -0,0 +1,332 @@
+//! Romanization of Arabic, and one spelling for names commonly romanized several ways.
+
+use unicode_normalization::UnicodeNormalization;
+
+/// Common names and their spellings, in Latin or in Arabic without vowel marks and with a
+/// plain alef. Spellings are lowercase.
+const NAMES: &[(&str, &[&str])] = &[
+    (
+        "Muhammad",
+        &[
+            "muhammad", "mohammed", "mohamed", "mohammad", "muhammed", "mohamad", "muhamed",
+            "mouhamed", "mohamud", "محمد",
+        ],
+    ),
+    ("Ahmad", &["ahmad", "ahmed", "احمد"]),
+    ("Mahmud", &["mahmud", "mahmoud", "mahmood", "محمود"]),
+    (
+        "Husayn",
+        &[
+            "husayn", "hussein", "husein", "hussain", "husain", "hossein", "حسين",
+        ],
+    ),
+    ("Hasan", &["hasan", "hassan", "حسن"]),
+    ("Ali", &["ali", "aly", "علي"]),
+    ("Umar", &["umar", "omar", "omer", "عمر"]),
+    ("Uthman", &["uthman", "othman", "osman", "usman", "عثمان"]),
+    (
+        "Yusuf",
+        &["yusuf", "youssef", "yousef", "yousif", "yusef", "يوسف"],
+    ),
+    ("Ibrahim", &["ibrahim", "ebrahim", "ibraheem", "ابراهيم"]),
+    ("Khalid", &["khalid", "khaled", "خالد"]),
+    (
+        "Mustafa",
+        &["mustafa", "moustafa", "mostafa", "mustapha", "مصطفي"],
+    ),
+    ("Abdullah", &["abdullah", "abdallah", "abdulla", "عبدالله"]),
+    ("Usama", &["usama", "osama", "oussama", "اسامة"]),
+    ("Said", &["said", "saeed", "saied", "سعيد"]),
+    ("Karim", &["karim", "kareem", "كريم"]),
+];
+
+const FATHATAN: char = '\u{064B}';
+const DAMMATAN: char = '\u{064C}';
+const KASRATAN: char = '\u{064D}';
+const FATHA: char = '\u{064E}';
+const DAMMA: char = '\u{064F}';
+const KASRA: char = '\u{0650}';
+const SHADDA: char = '\u{0651}';
+const SUKUN: char = '\u{0652}';
+const SUPERSCRIPT_ALEF: char = '\u{0670}';
+
+/// Latin spellings of the sun letters, which the article's "l" is assimilated to, each with
+/// the article as it is then written. Digraphs come first.
+const SUN_LETTERS: &[(&str, &str)] = &[
+    ("th", "ath"),
+    ("dh", "adh"),
+    ("sh", "ash"),
+    ("t", "at"),
+    ("d", "ad"),
+    ("r", "ar"),
+    ("z", "az"),
+    ("s", "as"),
+    ("n", "an"),
+];
+
+fn is_mark(c: char) -> bool {
+    matches!(c, FATHATAN..=SUKUN | SUPERSCRIPT_ALEF)
+}
+
+fn is_sun_letter(c: char) -> bool {
+    matches!(
+        c,
+        'ت' | 'ث' | 'د' | 'ذ' | 'ر' | 'ز' | 'س' | 'ش' | 'ص' | 'ض' | 'ط' | 'ظ' | 'ل' | 'ن'
+    )
+}
+
+/// Settings for romanizing Arabic. All options are on by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArabicRomanization {
+    short_vowels: bool,
+    article: bool,
+    name_variants: bool,
+}
+
+impl ArabicRomanization {
+    pub fn new() -> Self {
+        ArabicRomanization {
+            short_vowels: true,
+            article: true,
+            name_variants: true,
+        }
+    }
+
+    /// Whether vowel marks are romanized when the text has them. Most names are written
+    /// without them, so turning this off makes vocalized and unvocalized spellings agree.
+    pub fn with_short_vowels(mut self, short_vowels: bool) -> Self {
+        self.short_vowels = short_vowels;
+        self
+    }
+
+    /// Whether the article starting a word is kept or dropped, whether written "ال" or, in
+    /// Latin, "al-", "el-" or an assimilated form such as "ar-". Kept, it is spelled "al", or
+    /// "a" and a doubled sun letter as in "ar-Rashid", so "الرشيد", "Al-Rashid" and "Arrashid"
+    /// agree.
+    pub fn with_article(mut self, article: bool) -> Self {
+        self.article = article;
+        self
+    }
+
+    /// Whether common names are rewritten to one spelling, in Arabic script and in Latin
+    /// alike, so "محمد", "Mohammed" and "Mohamed" all become "Muhammad".
+    pub fn with_name_variants(mut self, name_variants: bool) -> Self {
+        self.name_variants = name_variants;
+        self
+    }
+
+    pub(crate) fn config(&self) -> String {
+        let options: Vec<&str> = [
+            (self.short_vowels, "short_vowels"),
+            (self.article, "article"),
+            (self.name_variants, "names"),
+        ]
+        .iter()
+        .filter(|(on, _)| *on)
+        .map(|(_, option)| *option)
+        .collect();
+
+        if options.is_empty() {
+            "none".to_string()
+        } else {
+            options.join("+")
+        }
+    }
+
+    /// Returns the canonical spelling of `word` if it is a known variant of a common name.
+    pub(crate) fn canonical_name(&self, word: &str) -> Option<&'static str> {
+        if !self.name_variants {
+            return None;
+        }
+
+        let key: String = word
+            .chars()
+            .filter(|&c| !is_mark(c))
+            .map(|c| match c {
+                'أ' | 'إ' | 'آ' | 'ٱ' => 'ا',
+                'ى' => 'ي',
+                _ => c,
+            })
+            .flat_map(char::to_lowercase)
+            .collect();
+
+        NAMES
+            .iter()
+            .find(|(_, spellings)| spellings.contains(&key.as_str()))
+            .map(|(name, _)| *name)
+    }
+
+    /// Returns the Latin spelling of `text[i]`, or `None` if it is not Arabic.
+    pub(crate) fn transliterate(&self, text: &[char], i: usize) -> Option<&'static str> {
+        let c = text[i];
+
+        if is_mark(c) {
+            if !self.short_vowels {
+                return Some("");
+            }
+            return Some(match c {
+                FATHATAN => "an",
+                DAMMATAN => "un",
+                KASRATAN => "in",
+                // Marks on a letter already written as a long vowel add nothing.
+                FATHA if next_letter(text, i).is_some_and(|n| matches!(n, 'ا' | 'ى')) => "",
+                DAMMA if next_letter(text, i) == Some('و') => "",
+                KASRA if next_letter(text, i) == Some('ي') => "",
+                FATHA | SUPERSCRIPT_ALEF => "a",
+                DAMMA => "u",
+                KASRA => "i",
+                // Doubles the consonant it sits on, unless the article already has.
+                SHADDA => match (0..i).rev().find(|&p| !is_mark(text[p])) {
+                    Some(p) if p > 0 && is_sun_letter(text[p]) && is_article(text, p - 1) => "",
+                    Some(p) => letter(text, p).unwrap_or(""),
+                    None => "",
+                },
+                _ => "",
+            });
+        }
+
+        if is_article(text, i) {
+            if !self.article {
+                return Some("");
+            }
+            // "ل" takes the sound of a sun letter after it.
+            let next = (i + 1..text.len()).find(|&p| !is_mark(text[p]));
+            if let Some(next) = next.filter(|&p| c == 'ل' && is_sun_letter(text[p])) {
+                return letter(text, next);
+            }
+        }
+
+        letter(text, i)
+    }
+
+    /// If a Latin article joined to a name by a hyphen, as in "al-Rashid" or "ar-Rashid",
+    /// starts at `text[i]`, returns where it ends, past the hyphen, and its spelling.
+    pub(crate) fn latin_article(&self, text: &[char], i: usize) -> Option<(usize, &'static str)> {
+        if i > 0 && text[i - 1].is_alphabetic() {
+            return None;
+        }
+        let hyphen = i + text[i..].iter().take_while(|c| c.is_alphabetic()).count();
+        if text.get(hyphen) != Some(&'-') {
+            return None;
+        }
+
+        let base = |c: &char| {
+            std::iter::once(*c)
+                .nfd()
+                .next()
+                .map_or(*c, |b| b.to_ascii_lowercase())
+        };
+        let prefix: String = text[i..hyphen].iter().map(base).collect();
+        let name: String = text[hyphen + 1..]
+            .iter()
+            .take_while(|c| c.is_alphabetic())
+            .take(2)
+            .map(base)
+            .collect();
+        if name.is_empty() {
+            return None;
+        }
+
+        let assimilated = SUN_LETTERS
+            .iter()
+            .find(|(sun, _)| name.starts_with(sun))
+            .map(|(_, article)| *article);
+        let is_article = matches!(prefix.as_str(), "al" | "el")
+            || assimilated
+                .is_some_and(|article| prefix.strip_prefix(['a', 'e']) == Some(&article[1..]));
+        if !is_article {
+            return None;
+        }
+
+        let spelling = match assimilated {
+            _ if !self.article => "",
+            Some(article) => article,
+            None => "al",
+        };
+        Some((hyphen + 1, spelling))
+    }
+}
+
+impl Default for ArabicRomanization {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Whether `text[i]` is part of an "ال" starting a word that continues after it.
+fn is_article(text: &[char], i: usize) -> bool {
+    let starts_word = |p: usize| p == 0 || !text[p - 1].is_alphabetic();
+    let is_alef = |c: char| matches!(c, 'ا' | 'ٱ');
+    let continues = |p: usize| text.get(p).is_some_and(|c| c.is_alphabetic());
+
+    (is_alef(text[i]) && text.get(i + 1) == Some(&'ل') && starts_word(i) && continues(i + 2))
+        || (text[i] == 'ل'
+            && i > 0
+            && is_alef(text[i - 1])
+            && starts_word(i - 1)
+            && continues(i + 1))
+}
+
+fn next_letter(text: &[char], i: usize) -> Option<char> {
+    text[i + 1..].iter().copied().find(|&c| !is_mark(c))
+}
+
+/// Whether the letter after `text[i]`, skipping a shadda, is a vowel or vowel mark, which
+/// makes "و" and "ي" consonants rather than long vowels.
+fn vowel_follows(text: &[char], i: usize) -> bool {
+    text[i + 1..]
+        .iter()
+        .find(|&&c| c != SHADDA)
+        .is_some_and(|&c| matches!(c, 'ا' | 'و' | 'ي' | 'ى' | FATHA | DAMMA | KASRA))
+}
+
+fn letter(text: &[char], i: usize) -> Option<&'static str> {
+    let starts_word = i == 0 || !text[i - 1].is_alphabetic() && !is_mark(text[i - 1]);
+
+    Some(match text[i] {
+        'ا' | 'أ' | 'آ' | 'ٱ' | 'ى' => "a",
+        'إ' => "i",
+        // Unwritten vowels are guessed only where a word would otherwise start with two
+        // consonants, as in "Abd" and "Walid".
+        'ع' if starts_word && !vowel_follows(text, i) => "a",
+        'ء' | 'ع' | '\u{0640}' => "",
+        'ؤ' => "u",
+        'ئ' => "i",
+        'ب' => "b",
+        'ت' => "t",
+        'ث' => "th",
+        'ج' => "j",
+        'ح' => "h",
+        'خ' => "kh",
+        'د' => "d",
+        'ذ' => "dh",
+        'ر' => "r",
+        'ز' => "z",
+        'س' => "s",
+        'ش' => "sh",
+        'ص' => "s",
+        'ض' => "d",
+        'ط' => "t",
+        'ظ' => "z",
+        'غ' => "gh",
+        'ف' => "f",
+        'ق' => "q",
+        'ك' | 'ک' => "k",
+        'ل' => "l",
+        'م' => "m",
+        'ن' => "n",
+        'ه' => "h",
+        'ة' => "a",
+        'و' if starts_word && !vowel_follows(text, i) => "wa",
+        'و' if starts_word || vowel_follows(text, i) => "w",
+        'و' => "u",
+        'ي' | 'ی' if starts_word && !vowel_follows(text, i) => "ya",
+        'ي' | 'ی' if starts_word || vowel_follows(text, i) => "y",
+        'ي' | 'ی' => "i",
+        'پ' => "p",
+        'چ' => "ch",
+        'ژ' => "zh",
+        'گ' => "g",
+        _ => return None,
+    })
+}
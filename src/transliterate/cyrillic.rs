@@ -0,0 +1,200 @@
+//! Romanization of Russian, Ukrainian and Belarusian Cyrillic. Text is read as Russian unless
+//! another [`CyrillicLanguage`] is set, since some letters are spelled differently per language.
+//!
+//! Hard and soft signs, and the apostrophes and backticks GOST 7.79 writes for some letters,
+//! are left out: encoders only take letters.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CyrillicScheme {
+    /// GOST 7.79-2000 system B, the ASCII-only Russian standard, e.g. "Shherbakov".
+    Gost779,
+    /// ISO 9:1995, one Latin letter per Cyrillic letter; its diacritics are folded away like
+    /// any other, e.g. "Scerbakov".
+    Iso9,
+    /// The BGN/PCGN-style spelling used in passports and the press, e.g. "Shcherbakov".
+    BgnPcgn,
+}
+
+/// The language Cyrillic text is read as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CyrillicLanguage {
+    #[default]
+    Russian,
+    /// "г" is "h" under BGN/PCGN, and "и" is "y" under both GOST 7.79 and BGN/PCGN, e.g.
+    /// "Hryhoriy" for "Григорій".
+    Ukrainian,
+    /// "г" is "h" and "ў" is "w" under BGN/PCGN, e.g. "Hrodna" for "Гродна".
+    Belarusian,
+}
+
+impl CyrillicLanguage {
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            CyrillicLanguage::Russian => "russian",
+            CyrillicLanguage::Ukrainian => "ukrainian",
+            CyrillicLanguage::Belarusian => "belarusian",
+        }
+    }
+}
+
+impl CyrillicScheme {
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            CyrillicScheme::Gost779 => "gost_7_79",
+            CyrillicScheme::Iso9 => "iso_9",
+            CyrillicScheme::BgnPcgn => "bgn_pcgn",
+        }
+    }
+
+    /// Returns the Latin spelling of the lowercase Cyrillic letter `c`, given the lowercase
+    /// letters around it, or `None` if `c` is not a letter the scheme covers.
+    pub(crate) fn transliterate(
+        self,
+        language: CyrillicLanguage,
+        c: char,
+        previous: Option<char>,
+        next: Option<char>,
+    ) -> Option<&'static str> {
+        use CyrillicLanguage::*;
+        use CyrillicScheme::*;
+
+        match (self, language, c) {
+            (BgnPcgn, Ukrainian | Belarusian, 'г') => return Some("h"),
+            (BgnPcgn, Belarusian, 'ў') => return Some("w"),
+            // Ukrainian "е" is never iotated; "є" is written for that.
+            (BgnPcgn, Ukrainian, 'е') => return Some("e"),
+            (Gost779 | BgnPcgn, Ukrainian, 'и') => return Some("y"),
+            _ => {}
+        }
+
+        match self {
+            CyrillicScheme::Gost779 => gost(c, next),
+            CyrillicScheme::Iso9 => iso9(c),
+            CyrillicScheme::BgnPcgn => bgn_pcgn(c, previous),
+        }
+    }
+}
+
+fn is_vowel(c: char) -> bool {
+    matches!(
+        c,
+        'а' | 'е' | 'ё' | 'и' | 'о' | 'у' | 'ы' | 'э' | 'ю' | 'я' | 'і' | 'ї' | 'є'
+    )
+}
+
+/// Spellings shared by GOST 7.79 and BGN/PCGN.
+fn common(c: char) -> Option<&'static str> {
+    Some(match c {
+        'а' => "a",
+        'б' => "b",
+        'в' => "v",
+        'г' => "g",
+        'д' => "d",
+        'е' => "e",
+        'ж' => "zh",
+        'з' => "z",
+        'и' => "i",
+        'к' => "k",
+        'л' => "l",
+        'м' => "m",
+        'н' => "n",
+        'о' => "o",
+        'п' => "p",
+        'р' => "r",
+        'с' => "s",
+        'т' => "t",
+        'у' => "u",
+        'ф' => "f",
+        'ч' => "ch",
+        'ш' => "sh",
+        'ъ' | 'ь' => "",
+        'ы' => "y",
+        'э' => "e",
+        'ю' => "yu",
+        'я' => "ya",
+        'і' => "i",
+        'ї' => "yi",
+        'є' => "ye",
+        'ґ' => "g",
+        'ў' => "u",
+        _ => return None,
+    })
+}
+
+fn gost(c: char, next: Option<char>) -> Option<&'static str> {
+    match c {
+        'ё' => Some("yo"),
+        'й' => Some("j"),
+        'х' => Some("x"),
+        // "c" before the letters written with i, e, y or j, "cz" elsewhere.
+        'ц' if next.is_some_and(|n| matches!(n, 'е' | 'и' | 'ы' | 'й' | 'і' | 'є')) => {
+            Some("c")
+        }
+        'ц' => Some("cz"),
+        'щ' => Some("shh"),
+        _ => common(c),
+    }
+}
+
+fn iso9(c: char) -> Option<&'static str> {
+    Some(match c {
+        'а' => "a",
+        'б' => "b",
+        'в' => "v",
+        'г' => "g",
+        'ґ' => "g\u{0300}",
+        'д' => "d",
+        'е' => "e",
+        'ё' => "ë",
+        'є' => "ê",
+        'ж' => "ž",
+        'з' => "z",
+        'и' => "i",
+        'і' => "ì",
+        'ї' => "ï",
+        'й' => "j",
+        'к' => "k",
+        'л' => "l",
+        'м' => "m",
+        'н' => "n",
+        'о' => "o",
+        'п' => "p",
+        'р' => "r",
+        'с' => "s",
+        'т' => "t",
+        'у' => "u",
+        'ў' => "ǔ",
+        'ф' => "f",
+        'х' => "h",
+        'ц' => "c",
+        'ч' => "č",
+        'ш' => "š",
+        'щ' => "ŝ",
+        'ъ' | 'ь' => "",
+        'ы' => "y",
+        'э' => "è",
+        'ю' => "û",
+        'я' => "â",
+        _ => return None,
+    })
+}
+
+fn bgn_pcgn(c: char, previous: Option<char>) -> Option<&'static str> {
+    // "Ye" and "yo" start a word and follow vowels and the signs; "yo" also follows other
+    // consonants, except the hushing ones.
+    let iotated =
+        previous.is_none_or(|p| !p.is_alphabetic() || is_vowel(p) || matches!(p, 'й' | 'ъ' | 'ь'));
+
+    match c {
+        'е' if iotated => Some("ye"),
+        'ё' if !iotated && previous.is_some_and(|p| matches!(p, 'ж' | 'ч' | 'ш' | 'щ')) => {
+            Some("o")
+        }
+        'ё' => Some("yo"),
+        'й' => Some("y"),
+        'х' => Some("kh"),
+        'ц' => Some("ts"),
+        'щ' => Some("shch"),
+        _ => common(c),
+    }
+}
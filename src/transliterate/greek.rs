@@ -0,0 +1,108 @@
+//! Romanization of Greek.
+
+use unicode_normalization::UnicodeNormalization;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GreekScheme {
+    /// ELOT 743 (ISO 843) transcription, as used for Greek passports, e.g. "Evangelos".
+    Elot743,
+}
+
+impl GreekScheme {
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            GreekScheme::Elot743 => "elot_743",
+        }
+    }
+
+    /// Returns the Latin spelling of `lower[i]`, or `None` if it is not a Greek letter. `lower`
+    /// is the text in lowercase; the second letter of a digraph romanizes to nothing.
+    pub(crate) fn transliterate(self, lower: &[char], i: usize) -> Option<&'static str> {
+        match self {
+            GreekScheme::Elot743 => elot743(lower, i),
+        }
+    }
+}
+
+/// Splits an accented letter into its base letter and whether it has a diaeresis, which
+/// keeps it from forming a digraph with the letter before.
+fn base(c: char) -> (char, bool) {
+    let mut decomposed = c.nfd();
+    let base = decomposed.next().unwrap_or(c);
+    (base, decomposed.any(|mark| mark == '\u{0308}'))
+}
+
+fn letter(lower: &[char], i: Option<usize>) -> Option<char> {
+    let c = *lower.get(i?)?;
+    c.is_alphabetic().then(|| base(c).0)
+}
+
+/// Whether the "υ" of "αυ", "ευ" or "ηυ" sounds as "f": before a voiceless consonant or at the
+/// end of a word.
+fn devoiced(next: Option<char>) -> bool {
+    next.is_none_or(|n| matches!(n, 'θ' | 'κ' | 'ξ' | 'π' | 'σ' | 'ς' | 'τ' | 'φ' | 'χ' | 'ψ'))
+}
+
+fn elot743(lower: &[char], i: usize) -> Option<&'static str> {
+    let (c, _) = base(lower[i]);
+    let previous = letter(lower, i.checked_sub(1));
+    let next = letter(lower, Some(i + 1));
+    // A "υ" without diaeresis after "α", "ε", "η" or "ο" is part of a digraph.
+    let upsilon_follows = lower.get(i + 1).is_some_and(|&n| base(n) == ('υ', false));
+    let after_upsilon = letter(lower, Some(i + 2));
+
+    Some(match c {
+        'α' if upsilon_follows => {
+            if devoiced(after_upsilon) {
+                "af"
+            } else {
+                "av"
+            }
+        }
+        'ε' if upsilon_follows => {
+            if devoiced(after_upsilon) {
+                "ef"
+            } else {
+                "ev"
+            }
+        }
+        'η' if upsilon_follows => {
+            if devoiced(after_upsilon) {
+                "if"
+            } else {
+                "iv"
+            }
+        }
+        'ο' if upsilon_follows => "ou",
+        'υ' if !base(lower[i]).1 && matches!(previous, Some('α' | 'ε' | 'η' | 'ο')) => "",
+        'γ' if matches!(next, Some('γ' | 'ξ' | 'χ')) => "n",
+        // "μπ" is "b" at the start of a word and "mp" elsewhere.
+        'μ' if next == Some('π') && previous.is_none() => "b",
+        'π' if previous == Some('μ') && letter(lower, i.checked_sub(2)).is_none() => "",
+        'α' => "a",
+        'β' => "v",
+        'γ' => "g",
+        'δ' => "d",
+        'ε' => "e",
+        'ζ' => "z",
+        'η' => "i",
+        'θ' => "th",
+        'ι' => "i",
+        'κ' => "k",
+        'λ' => "l",
+        'μ' => "m",
+        'ν' => "n",
+        'ξ' => "x",
+        'ο' => "o",
+        'π' => "p",
+        'ρ' => "r",
+        'σ' | 'ς' => "s",
+        'τ' => "t",
+        'υ' => "y",
+        'φ' => "f",
+        'χ' => "ch",
+        'ψ' => "ps",
+        'ω' => "o",
+        _ => return None,
+    })
+}
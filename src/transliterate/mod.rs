@@ -0,0 +1,346 @@
+mod arabic;
+mod cyrillic;
+mod greek;
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
+
+use crate::error::PhoneticError;
+use crate::explain::{ExplainStage, ExplainStep, Tracer};
+
+pub use arabic::ArabicRomanization;
+pub use cyrillic::{CyrillicLanguage, CyrillicScheme};
+pub use greek::GreekScheme;
+
+/// Letters that compatibility decomposition leaves alone, spelled out in plain Latin letters.
+const LETTERS: &[(char, &str)] = &[
+    ('æ', "ae"),
+    ('Æ', "AE"),
+    ('œ', "oe"),
+    ('Œ', "OE"),
+    ('ß', "ss"),
+    ('ẞ', "SS"),
+    ('ø', "o"),
+    ('Ø', "O"),
+    ('ł', "l"),
+    ('Ł', "L"),
+    ('đ', "d"),
+    ('Đ', "D"),
+    ('ð', "d"),
+    ('Ð', "D"),
+    ('þ', "th"),
+    ('Þ', "TH"),
+    ('ı', "i"),
+    ('ħ', "h"),
+    ('Ħ', "H"),
+];
+
+/// Rewrites text into plain letters before encoding: compatibility decomposition (NFKD), which
+/// also expands ligatures such as "ﬁ", removal of diacritics, and spelling out of letters like
+/// "æ", "ß" or "ø" that carry no diacritic to remove.
+///
+/// Other scripts are romanized once a scheme is chosen for them. Custom mappings, such as "ü"
+/// to "ue" for German names, take precedence over all of these.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Transliterator {
+    mappings: BTreeMap<char, String>,
+    cyrillic: Option<CyrillicScheme>,
+    cyrillic_language: CyrillicLanguage,
+    greek: Option<GreekScheme>,
+    arabic: Option<ArabicRomanization>,
+}
+
+impl Transliterator {
+    pub fn new() -> Self {
+        Transliterator {
+            mappings: BTreeMap::new(),
+            cyrillic: None,
+            cyrillic_language: CyrillicLanguage::Russian,
+            greek: None,
+            arabic: None,
+        }
+    }
+
+    /// Romanizes Cyrillic letters with `scheme`; without one they are left as they are.
+    pub fn with_cyrillic(mut self, scheme: CyrillicScheme) -> Self {
+        self.cyrillic = Some(scheme);
+        self
+    }
+
+    /// Reads Cyrillic text as `language`, Russian by default.
+    pub fn with_cyrillic_language(mut self, language: CyrillicLanguage) -> Self {
+        self.cyrillic_language = language;
+        self
+    }
+
+    /// Romanizes Greek letters with `scheme`; without one they are left as they are.
+    pub fn with_greek(mut self, scheme: GreekScheme) -> Self {
+        self.greek = Some(scheme);
+        self
+    }
+
+    /// Romanizes Arabic letters as set by `romanization`, which also applies to the spelling
+    /// of common names written in Latin; without it Arabic is left as it is.
+    pub fn with_arabic(mut self, romanization: ArabicRomanization) -> Self {
+        self.arabic = Some(romanization);
+        self
+    }
+
+    /// Maps the precomposed `from` to `to`, which is used as is.
+    pub fn with_mapping(mut self, from: char, to: impl Into<String>) -> Self {
+        self.mappings.insert(from, to.into());
+        self
+    }
+
+    /// Adds every mapping of `table`, as [`Transliterator::with_mapping`].
+    pub fn with_table(mut self, table: &[(char, &str)]) -> Self {
+        for &(from, to) in table {
+            self.mappings.insert(from, to.to_string());
+        }
+        self
+    }
+
+    pub fn transliterate(&self, val: &str) -> String {
+        self.transliterate_traced(val, &mut Tracer::disabled())
+    }
+
+    /// Like [`Transliterator::transliterate`], recording each character that was rewritten.
+    /// Spans are char offsets within `val` after canonical composition (NFC).
+    pub(crate) fn transliterate_traced(&self, val: &str, tracer: &mut Tracer) -> String {
+        let mut output = String::with_capacity(val.len());
+        // Compose first so that custom mappings of precomposed letters also match input
+        // written with combining marks.
+        let chars: Vec<char> = val.nfc().collect();
+        let lower: Vec<char> = chars.iter().map(|&c| to_lower(c)).collect();
+
+        let mut i = 0;
+        while i < chars.len() {
+            let start = output.len();
+
+            if let Some((end, article)) = self.latin_article(&chars, i) {
+                let all_capitals = chars[i..end - 1].iter().all(|c| !c.is_lowercase());
+                output.push_str(&match_case(article, chars[i], all_capitals));
+                let input: String = chars[i..end].iter().collect();
+                tracer.record(|| {
+                    ExplainStep::new(
+                        ExplainStage::Preprocess,
+                        "article",
+                        i..end,
+                        input,
+                        &output[start..],
+                    )
+                });
+                i = end;
+                continue;
+            }
+
+            if let Some((end, name)) = self.canonical_name(&chars, i) {
+                let all_capitals = chars[i..end].iter().all(|c| !c.is_lowercase());
+                output.push_str(&match_case(name, chars[i], all_capitals));
+                let input: String = chars[i..end].iter().collect();
+                tracer.record(|| {
+                    ExplainStep::new(
+                        ExplainStage::Preprocess,
+                        "name variant",
+                        i..end,
+                        input,
+                        &output[start..],
+                    )
+                });
+                i = end;
+                continue;
+            }
+
+            let c = chars[i];
+            if let Some(mapped) = self.mappings.get(&c) {
+                output.push_str(mapped);
+            } else if let Some(romanized) = self.romanize(&chars, &lower, i) {
+                output.extend(fold(&romanized));
+            } else if let Some((_, spelled)) = LETTERS.iter().find(|(letter, _)| *letter == c) {
+                output.push_str(spelled);
+            } else {
+                output.extend(fold(&c.to_string()));
+            }
+
+            if output[start..].chars().ne(std::iter::once(c)) {
+                tracer.record(|| {
+                    ExplainStep::new(
+                        ExplainStage::Preprocess,
+                        "transliterate",
+                        i..i + 1,
+                        c,
+                        &output[start..],
+                    )
+                });
+            }
+            i += 1;
+        }
+
+        output
+    }
+
+    /// If a word starts at `chars[i]` and is a known variant of a common name, returns where
+    /// the word ends and the name's canonical spelling.
+    fn canonical_name(&self, chars: &[char], i: usize) -> Option<(usize, &'static str)> {
+        let arabic = self.arabic?;
+        let in_word = |c: char| c.is_alphabetic() || is_combining_mark(c);
+        if !in_word(chars[i]) || (i > 0 && in_word(chars[i - 1])) {
+            return None;
+        }
+
+        let end = i + chars[i..].iter().take_while(|&&c| in_word(c)).count();
+        let word: String = chars[i..end].iter().collect();
+        arabic.canonical_name(&word).map(|name| (end, name))
+    }
+
+    /// If a Latin article joined to an Arabic name, such as "al-", starts at `chars[i]`,
+    /// returns where it ends and how it is spelled.
+    fn latin_article(&self, chars: &[char], i: usize) -> Option<(usize, &'static str)> {
+        self.arabic?.latin_article(chars, i)
+    }
+
+    /// Romanizes `chars[i]` with the scheme for its script, matching its case. `lower` is
+    /// `chars` in lowercase.
+    fn romanize(&self, chars: &[char], lower: &[char], i: usize) -> Option<String> {
+        let romanized = self.romanize_lower(lower, i)?;
+        // A letter is taken to be in an all-capitals word if a neighbour is a capital too.
+        let all_capitals = chars
+            .get(i + 1)
+            .or(i.checked_sub(1).map(|p| &chars[p]))
+            .is_some_and(|n| n.is_uppercase());
+
+        Some(match_case(romanized, chars[i], all_capitals).into_owned())
+    }
+
+    fn romanize_lower(&self, lower: &[char], i: usize) -> Option<&'static str> {
+        let previous = i.checked_sub(1).map(|p| lower[p]);
+        let next = lower.get(i + 1).copied();
+
+        None.or_else(|| {
+            self.cyrillic?
+                .transliterate(self.cyrillic_language, lower[i], previous, next)
+        })
+        .or_else(|| self.greek?.transliterate(lower, i))
+        .or_else(|| self.arabic?.transliterate(lower, i))
+    }
+
+    /// Whether `c` is rewritten by a custom mapping or the scheme for its script, which lets
+    /// encoders accept letters outside the Latin script.
+    pub(crate) fn supports(&self, c: char) -> bool {
+        self.mappings.contains_key(&c) || self.romanize_lower(&[to_lower(c)], 0).is_some()
+    }
+
+    /// Like [`Transliterator::supports`] for `chars[i]`, also accepting the hyphen that joins
+    /// an article to an Arabic name, as in "al-Rashid".
+    pub(crate) fn supports_at(&self, chars: &[char], i: usize) -> bool {
+        let word = i - chars[..i]
+            .iter()
+            .rev()
+            .take_while(|c| c.is_alphabetic())
+            .count();
+        self.supports(chars[i]) || (chars[i] == '-' && self.latin_article(chars, word).is_some())
+    }
+
+    /// The settings as comma-separated "key=value" pairs, empty for the default. Custom
+    /// mappings are listed as "from:to" pairs separated by ';', with a backslash before any
+    /// of `\,;:=` in them so that the string is never ambiguous.
+    pub(crate) fn config(&self) -> String {
+        let mut config = Vec::new();
+
+        if let Some(scheme) = self.cyrillic {
+            match self.cyrillic_language {
+                CyrillicLanguage::Russian => config.push(format!("cyrillic={}", scheme.name())),
+                language => config.push(format!("cyrillic={}+{}", scheme.name(), language.name())),
+            }
+        }
+        if let Some(scheme) = self.greek {
+            config.push(format!("greek={}", scheme.name()));
+        }
+        if let Some(romanization) = self.arabic {
+            config.push(format!("arabic={}", romanization.config()));
+        }
+        if !self.mappings.is_empty() {
+            let mappings: Vec<String> = self
+                .mappings
+                .iter()
+                .map(|(from, to)| {
+                    format!("{}:{}", escape_config(&from.to_string()), escape_config(to))
+                })
+                .collect();
+            config.push(format!("transliterate={}", mappings.join(";")));
+        }
+
+        config.join(",")
+    }
+}
+
+/// Escapes the characters that separate settings and mappings in a config string.
+fn escape_config(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '\\' | ',' | ';' | ':' | '=') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Spells `val` in plain letters as the default [`Transliterator`] does, for encoders whose
+/// rules only cover A–Z. Letters with diacritics become their base letters and the likes of
+/// "ß" and "ø" are spelled out, so "Müller" codes as "Muller" rather than losing its "ü".
+pub(crate) fn fold_latin(val: &str) -> String {
+    Transliterator::new().transliterate(val)
+}
+
+/// The letters of `val` after [`fold_latin`], in capitals, leaving out anything else. Fails
+/// when no letter from A to Z is left.
+pub(crate) fn ascii_letters(val: &str) -> Result<Vec<char>, PhoneticError> {
+    let letters: Vec<char> = fold_latin(val)
+        .chars()
+        .filter(|c| c.is_ascii_alphabetic())
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+
+    if letters.is_empty() {
+        return Err(PhoneticError::UnsupportedScript {
+            position: 0,
+            char: val.chars().next().unwrap_or_default(),
+        });
+    }
+
+    Ok(letters)
+}
+
+/// Decomposes `val` (NFKD) and drops its diacritics.
+fn fold(val: &str) -> impl Iterator<Item = char> + '_ {
+    val.nfkd().filter(|&c| !is_combining_mark(c))
+}
+
+fn to_lower(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+/// Puts a romanization in the case of the text it came from, whose first letter is `first`:
+/// lowercase, capitals if the text is in capitals, or otherwise with a capital first letter.
+/// Text from scripts without case keeps the romanization as it is.
+fn match_case(romanized: &str, first: char, all_capitals: bool) -> Cow<'_, str> {
+    if first.is_lowercase() {
+        return Cow::Owned(romanized.to_lowercase());
+    }
+    if !first.is_uppercase() {
+        return Cow::Borrowed(romanized);
+    }
+    if all_capitals {
+        return Cow::Owned(romanized.to_uppercase());
+    }
+
+    let mut letters = romanized.chars();
+    match letters.next() {
+        Some(first) => Cow::Owned(first.to_uppercase().chain(letters).collect()),
+        None => Cow::Borrowed(romanized),
+    }
+}
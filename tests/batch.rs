@@ -0,0 +1,96 @@
+use phonetics::{BatchAnnotator, BatchError, ColumnSelector, Metaphone, Nysiis, PhoneticEncoder};
+
+fn encoders() -> Vec<Box<dyn PhoneticEncoder>> {
+    vec![Box::new(Metaphone::new()), Box::new(Nysiis::new())]
+}
+
+fn annotate(annotator: &BatchAnnotator, input: &str) -> (String, usize, usize) {
+    let mut output = Vec::new();
+    let summary = annotator.annotate(input.as_bytes(), &mut output).unwrap();
+    (
+        String::from_utf8(output).unwrap(),
+        summary.rows,
+        summary.rows_with_errors,
+    )
+}
+
+#[test]
+fn test_annotate_csv() {
+    let annotator = BatchAnnotator::new("surname", encoders());
+    let input = "id,surname\n1,Smith\n2,Knight\n";
+
+    let (output, rows, errors) = annotate(&annotator, input);
+    assert_eq!(
+        output,
+        "id,surname,surname_metaphone,surname_nysiis,surname_error\n\
+         1,Smith,sm0,SNAT,\n\
+         2,Knight,nht,NAGT,\n"
+    );
+    assert_eq!((rows, errors), (2, 0));
+}
+
+#[test]
+fn test_errors_are_reported_per_row() {
+    let annotator = BatchAnnotator::new("surname", encoders());
+    let input = "id,surname\n1,O'Brien\n2,\n3,Jones\n";
+
+    let (output, rows, errors) = annotate(&annotator, input);
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(
+        lines[1],
+        "1,O'Brien,,OBRAN,metaphone: non-alphabetic character '\\'' at position 1"
+    );
+    assert!(lines[2].starts_with("2,,,,metaphone: input is empty; nysiis: input is empty"));
+    assert_eq!(lines[3], "3,Jones,jns,JAN,");
+    assert_eq!((rows, errors), (3, 2));
+}
+
+#[test]
+fn test_tsv_and_column_index() {
+    let annotator =
+        BatchAnnotator::new(1, vec![Box::new(Nysiis::new()) as Box<dyn PhoneticEncoder>])
+            .with_delimiter(b'\t');
+    let input = "id\tname\textra\n7\tBailey\tx\n";
+
+    let (output, _, _) = annotate(&annotator, input);
+    assert_eq!(
+        output,
+        "id\tname\textra\tname_nysiis\tname_error\n7\tBailey\tx\tBALY\t\n"
+    );
+}
+
+#[test]
+fn test_short_rows() {
+    let annotator = BatchAnnotator::new("surname", encoders());
+    let (output, _, errors) = annotate(&annotator, "id,surname\n1\n");
+    assert_eq!(
+        output.lines().nth(1).unwrap(),
+        "1,,,,metaphone: input is empty; nysiis: input is empty"
+    );
+    assert_eq!(errors, 1);
+}
+
+#[test]
+fn test_long_rows() {
+    let annotator = BatchAnnotator::new("surname", encoders());
+    let (output, rows, errors) = annotate(&annotator, "id,surname\n1,Smith,x,y\n2,Jones\n");
+    assert_eq!(
+        output,
+        "id,surname,surname_metaphone,surname_nysiis,surname_error\n\
+         1,Smith,sm0,SNAT,2 fields beyond the 2 of the header dropped\n\
+         2,Jones,jns,JAN,\n"
+    );
+    assert_eq!((rows, errors), (2, 1));
+}
+
+#[test]
+fn test_missing_column() {
+    let annotator = BatchAnnotator::new("given", encoders());
+    let err = annotator
+        .annotate("id,surname\n".as_bytes(), Vec::new())
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        BatchError::MissingColumn(ColumnSelector::Name(ref name)) if name == "given"
+    ));
+}
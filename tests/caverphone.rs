@@ -0,0 +1,46 @@
+use phonetics::{Caverphone, CaverphoneVersion, PhoneticEncoder, PhoneticError};
+
+#[test]
+fn test_caverphone_two() {
+    let c = Caverphone::new();
+    assert_eq!(c.encode("Thompson"), "TMPSN11111");
+    assert_eq!(c.encode("Stevenson"), "STFNSN1111");
+    assert_eq!(c.encode("Peter"), "PTA1111111");
+    assert_eq!(c.encode("Tomlinson"), "TMLNSN1111");
+    assert_eq!(c.encode("Whitlam"), "WTLM111111");
+    assert_eq!(c.encode("Izchaki"), "ASKKA11111");
+}
+
+#[test]
+fn test_caverphone_one() {
+    let c = Caverphone::with_version(CaverphoneVersion::One);
+    assert_eq!(c.encode("Henrichsen"), "ANRKSN");
+    assert_eq!(c.encode("Henriksson"), "ANRKSN");
+    assert_eq!(c.encode("Slocomb"), "SLKM11");
+    assert_eq!(c.encode("Slocombe"), "SLKMP1");
+    assert_eq!(c.encode("Whitlam"), "WTLM11");
+    assert_eq!(c.encode("Izchaki"), "ASKK11");
+}
+
+#[test]
+fn test_caverphone_compare() {
+    let c = Caverphone::new();
+    assert!(c.compare("Maclaverty", "Mclafferty"));
+    assert!(c.compare("Henrichsen", "Hinrichsen"));
+    assert!(!c.compare("Whitlam", "Thompson"));
+
+    let c = Caverphone::with_version(CaverphoneVersion::One);
+    assert!(c.compare("Slocomb", "Slocumb"));
+}
+
+#[test]
+fn test_encoder_trait() {
+    let c = Caverphone::with_version(CaverphoneVersion::One);
+    assert_eq!(c.name(), "caverphone1");
+    assert_eq!(c.max_code_length(), Some(6));
+    assert_eq!(Caverphone::new().max_code_length(), Some(10));
+
+    assert_eq!(c.try_encode(""), Err(PhoneticError::Empty));
+    assert!(c.try_encode("1234").is_err());
+    assert_eq!(c.encode(""), "111111");
+}
@@ -0,0 +1,158 @@
+use phonetics::{
+    ChinesePhonetic, ChineseRomanization, PhoneticEncoder, PhoneticError, encoder_by_name,
+};
+
+fn same(val1: &str, val2: &str) -> bool {
+    ChinesePhonetic::new().compare(val1, val2).unwrap()
+}
+
+#[test]
+fn test_cross_system_surnames() {
+    for name in ["Zhang", "Chang", "Cheung", "Zhāng"] {
+        assert!(same(name, "Zhang"), "{name}");
+    }
+    for name in ["Xu", "Hsu", "Hsü", "Hui", "Tsui"] {
+        assert!(same(name, "Xu"), "{name}");
+    }
+    assert!(same("Ch'en", "Chen"));
+    assert!(same("Chan", "Chen"));
+    assert!(same("Hsieh", "Xie"));
+    assert!(same("Tse", "Xie"));
+    assert!(same("Shih", "Shi"));
+    assert!(!same("Zhang", "Zhao"));
+}
+
+#[test]
+fn test_ambiguous_spellings() {
+    let encoder = ChinesePhonetic::new();
+    assert_eq!(encoder.encode("Wong").unwrap(), ["HUANG", "WANG"]);
+    assert!(same("Wong", "Huang"));
+    assert!(same("Wong", "Wang"));
+    assert!(!same("Huang", "Wang"));
+
+    // Without the apostrophe Wade–Giles cannot tell aspirated initials apart.
+    assert_eq!(encoder.encode("Chang").unwrap(), ["CHANG", "ZHANG"]);
+    assert_eq!(encoder.encode("Ch'ang").unwrap(), ["CHANG"]);
+    assert_eq!(encoder.encode("Lu").unwrap(), ["LU", "LV"]);
+    assert_eq!(encoder.encode("Lyu").unwrap(), ["LV"]);
+}
+
+#[test]
+fn test_compound_surnames() {
+    for name in ["Ouyang", "Ou-yang", "Au-Yeung", "Auyeung", "Ou'yang"] {
+        assert_eq!(
+            ChinesePhonetic::new().encode(name).unwrap(),
+            ["OUYANG"],
+            "{name}"
+        );
+    }
+    assert!(same("Ssu-ma", "Sima"));
+    assert!(same("Szeto", "Situ"));
+    assert_eq!(ChinesePhonetic::new().encode("Xi'an").unwrap(), ["XIAN"]);
+}
+
+#[test]
+fn test_full_names() {
+    let encoder = ChinesePhonetic::new();
+    for name in ["Zhang Wei", "Zhang-Wei", "Zhangwei", "Chang Wei"] {
+        assert!(
+            encoder
+                .encode(name)
+                .unwrap()
+                .contains(&"ZHANGWEI".to_string()),
+            "{name}"
+        );
+    }
+    assert!(same("Chang Wei", "Zhang  Wei"));
+    assert!(!same("Zhang Wei", "Zhang Li"));
+}
+
+#[test]
+fn test_too_many_readings() {
+    let encoder = ChinesePhonetic::new();
+    // Each "Wong" reads as "Huang" or "Wang": 2^6 keys are kept, 2^7 are not.
+    assert_eq!(
+        encoder
+            .encode("Wong Wong Wong Wong Wong Wong")
+            .unwrap()
+            .len(),
+        64
+    );
+    assert_eq!(
+        encoder.encode("Wong Wong Wong Wong Wong Wong Wong"),
+        Err(PhoneticError::TooManyReadings { limit: 64 })
+    );
+}
+
+#[test]
+fn test_romanizations() {
+    let pinyin = ChinesePhonetic::new().with_romanizations([ChineseRomanization::Pinyin]);
+    assert_eq!(pinyin.encode("Chang").unwrap(), ["CHANG"]);
+    assert!(!pinyin.compare("Cheung", "Zhang").unwrap());
+    assert_eq!(pinyin.config(), "romanizations=pinyin");
+
+    let encoder = encoder_by_name("chinese").unwrap();
+    assert_eq!(encoder.encode("Wong").unwrap(), "HUANG|WANG");
+    assert!(encoder.compare("Cheung", "Zhang").unwrap());
+    assert_eq!(
+        encoder.config(),
+        "romanizations=pinyin+wade_giles+cantonese"
+    );
+    assert_eq!(encoder.max_code_length(), None);
+}
+
+#[test]
+fn test_errors() {
+    let encoder = ChinesePhonetic::new();
+    assert_eq!(encoder.encode("").unwrap_err(), PhoneticError::Empty);
+    assert_eq!(
+        encoder.encode("Mao Zedong2").unwrap_err(),
+        PhoneticError::NonAlphabetic {
+            position: 10,
+            char: '2'
+        }
+    );
+    assert_eq!(
+        encoder.encode("Σ").unwrap_err(),
+        PhoneticError::UnsupportedScript {
+            position: 0,
+            char: 'Σ'
+        }
+    );
+}
+
+#[cfg(feature = "han")]
+#[test]
+fn test_han_surnames() {
+    assert!(same("张", "Cheung"));
+    assert!(same("張", "Zhang"));
+    assert!(same("黃", "Wong"));
+    assert!(same("歐陽", "Au-Yeung"));
+    assert!(same("诸葛", "Zhuge"));
+
+    // Given names are read in both scripts.
+    assert!(same("张伟", "Zhang-Wei"));
+    assert!(same("張伟", "Zhang Wei"));
+    assert!(same("张 伟", "Chang Wei"));
+    assert!(same("歐陽娜娜", "Au-Yeung Na-na"));
+    assert!(!same("张伟", "Zhang"));
+    assert_eq!(
+        ChinesePhonetic::new().encode("张龘").unwrap_err(),
+        PhoneticError::UnsupportedScript {
+            position: 1,
+            char: '龘'
+        }
+    );
+}
+
+#[cfg(not(feature = "han"))]
+#[test]
+fn test_han_needs_feature() {
+    assert_eq!(
+        ChinesePhonetic::new().encode("张").unwrap_err(),
+        PhoneticError::UnsupportedScript {
+            position: 0,
+            char: '张'
+        }
+    );
+}
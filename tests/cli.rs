@@ -0,0 +1,97 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn phonetics(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_phonetics"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn test_encode_arguments() {
+    let output = phonetics(&["encode", "-a", "nysiis", "Smith", "Knight"], "");
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "Smith\tSNAT\nKnight\tNAGT\n");
+}
+
+#[test]
+fn test_encode_stdin_lines() {
+    let output = phonetics(&["encode"], "knight\n\n  night \n");
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "knight\tnht\nnight\tnht\n");
+}
+
+#[test]
+fn test_csv_column() {
+    let input = "id;surname\n1;Smith\n2;Smyth\n";
+    let output = phonetics(
+        &[
+            "group",
+            "--algorithm",
+            "soundex",
+            "-c",
+            "surname",
+            "-d",
+            ";",
+        ],
+        input,
+    );
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "S530\tSmith\tSmyth\n");
+
+    let output = phonetics(&["group", "-a", "soundex", "-c", "1", "-d", ";"], input);
+    assert_eq!(stdout(&output), "S530\tSmith\tSmyth\n");
+}
+
+#[test]
+fn test_compare_pairs() {
+    let output = phonetics(
+        &["compare", "-a", "soundex", "Robert", "Rupert", "Rubin"],
+        "",
+    );
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "Robert\tRupert\ttrue\nRobert\tRubin\tfalse\nRupert\tRubin\tfalse\n"
+    );
+}
+
+#[test]
+fn test_errors() {
+    let output = phonetics(&["encode", "Smith", "Sm1th"], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout(&output), "Smith\tsm0\n");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Sm1th"));
+
+    let output = phonetics(&["encode", "-a", "soundx", "Smith"], "");
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn test_annotate() {
+    let output = phonetics(
+        &["annotate", "-c", "name", "-a", "soundex", "-a", "nysiis"],
+        "name\nSmith\n",
+    );
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "name,name_soundex,name_nysiis,name_error\nSmith,S530,SNAT,\n"
+    );
+}
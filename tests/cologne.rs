@@ -0,0 +1,58 @@
+use phonetics::{ColognePhonetic, PhoneticEncoder, PhoneticError};
+
+#[test]
+fn test_cologne_phonetic() {
+    let c = ColognePhonetic::new();
+    assert_eq!(c.encode("Wikipedia").unwrap(), "3412");
+    assert_eq!(c.encode("Breschnew").unwrap(), "17863");
+    assert_eq!(c.encode("Meyer").unwrap(), "67");
+    assert_eq!(c.encode("Mayr").unwrap(), "67");
+    assert_eq!(c.encode("Aachen").unwrap(), "046");
+    assert_eq!(c.encode("Xaver").unwrap(), "4837");
+}
+
+#[test]
+fn test_umlauts_and_eszett() {
+    let c = ColognePhonetic::new();
+    assert_eq!(c.encode("Müller").unwrap(), "657");
+    assert_eq!(c.encode("Mueller").unwrap(), "657");
+    assert_eq!(c.encode("Strauß").unwrap(), "8278");
+    assert_eq!(c.encode("Strauss").unwrap(), "8278");
+    assert_eq!(c.encode("Ärger").unwrap(), "0747");
+    assert_eq!(c.encode("Öl").unwrap(), "05");
+    assert_eq!(c.encode("Øster").unwrap(), c.encode("Oster").unwrap());
+    assert_eq!(c.encode("é").unwrap(), "0");
+    assert_eq!(
+        c.encode("ƿ").unwrap_err(),
+        PhoneticError::UnsupportedScript {
+            position: 0,
+            char: 'ƿ'
+        }
+    );
+}
+
+#[test]
+fn test_context_rules() {
+    let c = ColognePhonetic::new();
+    // C at the start of a word
+    assert_eq!(c.encode("Cäsar").unwrap(), "487");
+    assert_eq!(c.encode("Celle").unwrap(), "85");
+    // C after S or Z
+    assert_eq!(c.encode("Schulz").unwrap(), "858");
+    // D and T before C, S, Z
+    assert_eq!(c.encode("Schmidt").unwrap(), "862");
+    assert_eq!(c.encode("Schmitz").unwrap(), "868");
+    // X after C, K, Q
+    assert_eq!(c.encode("Dachs").unwrap(), "248");
+    assert_eq!(c.encode("Hexe").unwrap(), "48");
+}
+
+#[test]
+fn test_cologne_compare() {
+    let c = ColognePhonetic::new();
+    assert!(c.compare("Meier", "Mayr").unwrap());
+    assert!(c.compare("Müller", "Mueller").unwrap());
+    assert!(!c.compare("Müller", "Schmidt").unwrap());
+    assert_eq!(c.encode(""), Err(PhoneticError::Empty));
+    assert_eq!(PhoneticEncoder::name(&c), "cologne_phonetic");
+}
@@ -0,0 +1,143 @@
+use phonetics::{
+    CyrillicLanguage, CyrillicScheme, Metaphone, Nysiis, PhoneticEncoder, PhoneticError,
+    Transliterator,
+};
+
+fn transliterate(scheme: CyrillicScheme, val: &str) -> String {
+    Transliterator::new()
+        .with_cyrillic(scheme)
+        .transliterate(val)
+}
+
+#[test]
+fn test_schemes() {
+    use CyrillicScheme::*;
+
+    assert_eq!(transliterate(Gost779, "Щербаков"), "Shherbakov");
+    assert_eq!(transliterate(Iso9, "Щербаков"), "Serbakov");
+    assert_eq!(transliterate(BgnPcgn, "Щербаков"), "Shcherbakov");
+
+    assert_eq!(transliterate(Gost779, "Хрущёв"), "Xrushhyov");
+    assert_eq!(transliterate(Iso9, "Хрущёв"), "Hrusev");
+    assert_eq!(transliterate(BgnPcgn, "Хрущёв"), "Khrushchov");
+
+    assert_eq!(transliterate(Gost779, "Сергей"), "Sergej");
+    assert_eq!(transliterate(BgnPcgn, "Сергей"), "Sergey");
+}
+
+#[test]
+fn test_context_rules() {
+    use CyrillicScheme::*;
+
+    // GOST writes "ц" as "c" before i, e, y and j sounds and "cz" elsewhere.
+    assert_eq!(transliterate(Gost779, "Ельцин Царёв"), "Elcin Czaryov");
+    // BGN/PCGN writes "е" as "ye" at the start of a word and after vowels.
+    assert_eq!(transliterate(BgnPcgn, "Ельцин Андреев"), "Yeltsin Andreyev");
+    assert_eq!(transliterate(BgnPcgn, "Фёдор Горбачёв"), "Fyodor Gorbachov");
+}
+
+#[test]
+fn test_case_and_ukrainian() {
+    let t = Transliterator::new().with_cyrillic(CyrillicScheme::BgnPcgn);
+
+    assert_eq!(t.transliterate("ЩЕРБАКОВ"), "SHCHERBAKOV");
+    assert_eq!(t.transliterate("Юлия"), "Yuliya");
+    assert_eq!(t.transliterate("Київ Єнакієве"), "Kiyiv Yenakiyeve");
+    assert_eq!(t.transliterate("Ольга Smith"), "Olga Smith");
+}
+
+#[test]
+fn test_languages() {
+    use CyrillicScheme::*;
+
+    let transliterate = |scheme, language, val| {
+        Transliterator::new()
+            .with_cyrillic(scheme)
+            .with_cyrillic_language(language)
+            .transliterate(val)
+    };
+
+    assert_eq!(
+        transliterate(BgnPcgn, CyrillicLanguage::Ukrainian, "Григорій Київ"),
+        "Hryhoriy Kyyiv"
+    );
+    assert_eq!(
+        transliterate(BgnPcgn, CyrillicLanguage::Ukrainian, "Олена Тетяна"),
+        "Olena Tetyana"
+    );
+    assert_eq!(
+        transliterate(Gost779, CyrillicLanguage::Ukrainian, "Григорій"),
+        "Grygorij"
+    );
+    assert_eq!(
+        transliterate(BgnPcgn, CyrillicLanguage::Belarusian, "Гродна Ўладзімір"),
+        "Hrodna Wladzimir"
+    );
+    assert_eq!(
+        transliterate(BgnPcgn, CyrillicLanguage::Russian, "Григорий"),
+        "Grigoriy"
+    );
+
+    let metaphone = Metaphone::new().with_transliterator(
+        Transliterator::new()
+            .with_cyrillic(BgnPcgn)
+            .with_cyrillic_language(CyrillicLanguage::Ukrainian),
+    );
+    assert!(metaphone.compare("Григорій", "Hryhoriy").unwrap());
+    assert_eq!(metaphone.config(), "cyrillic=bgn_pcgn+ukrainian");
+}
+
+#[test]
+fn test_encoders_match_passport_spellings() {
+    let t = Transliterator::new().with_cyrillic(CyrillicScheme::BgnPcgn);
+
+    let metaphone = Metaphone::new().with_transliterator(t.clone());
+    assert!(metaphone.compare("Щербаков", "Shcherbakov").unwrap());
+    assert!(metaphone.compare("Хрущёв", "Khrushchov").unwrap());
+
+    let nysiis = Nysiis::new().with_transliterator(t);
+    assert_eq!(nysiis.encode("Щербаков"), nysiis.encode("Shcherbakov"));
+    assert_eq!(nysiis.try_encode("Щербаков").unwrap(), "SCARBA");
+    assert_eq!(
+        nysiis.config(),
+        "variant=extended,max_length=6,cyrillic=bgn_pcgn"
+    );
+}
+
+#[test]
+fn test_decomposed_input() {
+    let metaphone = Metaphone::new()
+        .with_transliterator(Transliterator::new().with_cyrillic(CyrillicScheme::BgnPcgn));
+    // "й" and "ё" typed as a base letter and a combining mark.
+    assert_eq!(
+        metaphone.encode("Андреи\u{0306}").unwrap(),
+        metaphone.encode("Андрей").unwrap()
+    );
+    assert_eq!(
+        metaphone.encode("Фе\u{0308}дор").unwrap(),
+        metaphone.encode("Fyodor").unwrap()
+    );
+}
+
+#[test]
+fn test_without_scheme() {
+    assert_eq!(
+        Metaphone::new().encode("Щербаков").unwrap_err(),
+        PhoneticError::UnsupportedScript {
+            position: 0,
+            char: 'Щ'
+        }
+    );
+    assert!(Nysiis::new().try_encode("Щербаков").is_err());
+
+    let metaphone = Metaphone::new()
+        .with_transliterator(Transliterator::new().with_cyrillic(CyrillicScheme::Iso9));
+    assert_eq!(
+        metaphone.encode("Щерб1").unwrap_err(),
+        PhoneticError::NonAlphabetic {
+            position: 4,
+            char: '1'
+        }
+    );
+    assert_eq!(metaphone.config(), "cyrillic=iso_9");
+}
@@ -0,0 +1,59 @@
+use phonetics::{DaitchMokotoff, PhoneticEncoder};
+
+fn codes(val: &[&str]) -> Vec<String> {
+    let mut codes: Vec<String> = val.iter().map(|c| c.to_string()).collect();
+    codes.sort();
+    codes
+}
+
+#[test]
+fn test_daitch_mokotoff() {
+    let dm = DaitchMokotoff::new();
+    assert_eq!(dm.encode("Moskowitz").unwrap(), codes(&["645740"]));
+    assert_eq!(dm.encode("Moskovitz").unwrap(), codes(&["645740"]));
+    assert_eq!(dm.encode("Lipshitz").unwrap(), codes(&["874400"]));
+    assert_eq!(dm.encode("Lewinsky").unwrap(), codes(&["876450"]));
+    assert_eq!(
+        dm.encode("Szlachter").unwrap(),
+        codes(&["485390", "484390"])
+    );
+}
+
+#[test]
+fn test_branching() {
+    let dm = DaitchMokotoff::new();
+    assert_eq!(dm.encode("Peters").unwrap(), codes(&["739400", "734000"]));
+    assert_eq!(dm.encode("Auerbach").unwrap(), codes(&["097400", "097500"]));
+    assert_eq!(
+        dm.encode("Jackson").unwrap(),
+        codes(&["154600", "454600", "145460", "445460"])
+    );
+    assert_eq!(dm.encode("Lippszyc").unwrap(), codes(&["874400", "874500"]));
+}
+
+#[test]
+fn test_merged_branches_and_diacritics() {
+    let dm = DaitchMokotoff::new();
+    // Both readings of "J" leave "4"; the merged branch then codes the "Z".
+    assert_eq!(dm.encode("Czjzek").unwrap(), codes(&["445000"]));
+    assert_eq!(dm.encode("Müller").unwrap(), dm.encode("Muller").unwrap());
+}
+
+#[test]
+fn test_daitch_mokotoff_compare() {
+    let dm = DaitchMokotoff::new();
+    assert!(dm.compare("Moskowitz", "Moskovitz").unwrap());
+    assert!(dm.compare("Lewinsky", "Levinsky").unwrap());
+    assert!(dm.compare("Lipshitz", "Lippszyc").unwrap());
+    assert!(!dm.compare("Peters", "Jackson").unwrap());
+    assert!(dm.compare("", "Peters").is_err());
+}
+
+#[test]
+fn test_trait_encode() {
+    let dm = DaitchMokotoff::new();
+    assert_eq!(
+        PhoneticEncoder::encode(&dm, "Peters").unwrap(),
+        "734000|739400"
+    );
+}
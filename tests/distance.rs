@@ -0,0 +1,65 @@
+use phonetics::distance::{
+    damerau_levenshtein, hamming, jaro, jaro_winkler, levenshtein, normalized_levenshtein,
+    osa_distance,
+};
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < 1e-3,
+        "expected {}, got {}",
+        expected,
+        actual
+    );
+}
+
+#[test]
+fn test_levenshtein() {
+    assert_eq!(levenshtein("kitten", "sitting"), 3);
+    assert_eq!(levenshtein("", "abc"), 3);
+    assert_eq!(levenshtein("Smith", "Smith"), 0);
+    // Counted in chars: "é" is one substitution, not two byte edits.
+    assert_eq!(levenshtein("José", "Jose"), 1);
+
+    assert_close(normalized_levenshtein("kitten", "sitting"), 1.0 - 3.0 / 7.0);
+    assert_close(normalized_levenshtein("", ""), 1.0);
+}
+
+#[test]
+fn test_transpositions() {
+    assert_eq!(levenshtein("ab", "ba"), 2);
+    assert_eq!(osa_distance("ab", "ba"), 1);
+    assert_eq!(damerau_levenshtein("ab", "ba"), 1);
+
+    // OSA may not edit a transposed pair again; true Damerau–Levenshtein may.
+    assert_eq!(osa_distance("ca", "abc"), 3);
+    assert_eq!(damerau_levenshtein("ca", "abc"), 2);
+
+    assert_eq!(osa_distance("Smtih", "Smith"), 1);
+    assert_eq!(damerau_levenshtein("", "abc"), 3);
+    assert_eq!(damerau_levenshtein("Ærø", "Ærø"), 0);
+}
+
+#[test]
+fn test_jaro() {
+    assert_close(jaro("MARTHA", "MARHTA"), 0.944);
+    assert_close(jaro("DIXON", "DICKSONX"), 0.767);
+    assert_close(jaro("abc", "xyz"), 0.0);
+    assert_close(jaro("", ""), 1.0);
+    assert_close(jaro("", "a"), 0.0);
+}
+
+#[test]
+fn test_jaro_winkler() {
+    assert_close(jaro_winkler("MARTHA", "MARHTA"), 0.961);
+    assert_close(jaro_winkler("DIXON", "DICKSONX"), 0.813);
+    assert_close(jaro_winkler("DWAYNE", "DUANE"), 0.84);
+    assert!(jaro_winkler("Zoë", "Zoe") > jaro("Zoë", "Zoe"));
+}
+
+#[test]
+fn test_hamming() {
+    assert_eq!(hamming("karolin", "kathrin"), Some(3));
+    assert_eq!(hamming("Müller", "Muller"), Some(1));
+    assert_eq!(hamming("abc", "ab"), None);
+    assert_eq!(hamming("", ""), Some(0));
+}
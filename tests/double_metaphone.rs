@@ -0,0 +1,53 @@
+use phonetics::{DoubleMetaphone, DoubleMetaphoneCode, PhoneticEncoder, PhoneticError};
+
+fn code(primary: &str, alternate: &str) -> DoubleMetaphoneCode {
+    DoubleMetaphoneCode {
+        primary: primary.to_string(),
+        alternate: alternate.to_string(),
+    }
+}
+
+#[test]
+fn test_double_metaphone() {
+    let dm = DoubleMetaphone::new();
+    assert_eq!(dm.encode("Schmidt").unwrap(), code("XMT", "SMT"));
+    assert_eq!(dm.encode("smith").unwrap(), code("SM0", "XMT"));
+    assert_eq!(dm.encode("Thompson").unwrap(), code("TMPS", "TMPS"));
+    assert_eq!(dm.encode("Jose").unwrap(), code("HS", "HS"));
+    assert_eq!(dm.encode("Xavier").unwrap(), code("SF", "SFR"));
+    assert_eq!(dm.encode("Knight").unwrap(), code("NT", "NT"));
+}
+
+#[test]
+fn test_non_english_origins() {
+    let dm = DoubleMetaphone::new();
+    assert_eq!(dm.encode("Filipowicz").unwrap(), code("FLPT", "FLPF"));
+    assert_eq!(dm.encode("Gallegos").unwrap(), code("KLKS", "KKS"));
+    assert_eq!(dm.encode("Cabrillo").unwrap(), code("KPRL", "KPR"));
+    assert_eq!(dm.encode("Zhang").unwrap(), code("JNK", "JNK"));
+    assert_eq!(dm.encode("Arnow").unwrap(), code("ARN", "ARNF"));
+}
+
+#[test]
+fn test_max_length() {
+    let dm = DoubleMetaphone::new().with_max_length(8);
+    assert_eq!(dm.encode("Jankelowicz").unwrap(), code("JNKLTS", "ANKLFX"));
+    assert_eq!(dm.max_code_length(), Some(8));
+}
+
+#[test]
+fn test_double_metaphone_compare() {
+    let dm = DoubleMetaphone::new();
+    assert!(dm.compare("Schmidt", "Smith").unwrap());
+    assert!(dm.compare("Arnow", "Arnoff").unwrap());
+    assert!(dm.compare("Jankowski", "Yankowski").unwrap());
+    assert!(!dm.compare("Smith", "Jones").unwrap());
+}
+
+#[test]
+fn test_error_handling() {
+    let dm = DoubleMetaphone::new();
+    assert_eq!(dm.encode(""), Err(PhoneticError::Empty));
+    assert!(dm.encode("123").is_err());
+    assert!(dm.compare("test", "").is_err());
+}
@@ -0,0 +1,54 @@
+use phonetics::{ENCODER_NAMES, Metaphone, Nysiis, PhoneticEncoder, encoder_by_name};
+
+#[test]
+fn test_trait_objects() {
+    let encoders: Vec<Box<dyn PhoneticEncoder>> =
+        vec![Box::new(Metaphone::new()), Box::new(Nysiis::new())];
+
+    let names: Vec<&str> = encoders.iter().map(|e| e.name()).collect();
+    assert_eq!(names, vec!["metaphone", "nysiis"]);
+
+    assert_eq!(encoders[0].encode("smith").unwrap(), "sm0");
+    assert_eq!(encoders[1].encode("Smith").unwrap(), "SNAT");
+}
+
+#[test]
+fn test_trait_compare() {
+    let nysiis = Nysiis::new();
+    assert!(PhoneticEncoder::compare(&nysiis, "Bailey", "Bailie").unwrap());
+    assert!(!PhoneticEncoder::compare(&nysiis, "Smith", "Jones").unwrap());
+
+    let metaphone = Metaphone::new();
+    assert!(PhoneticEncoder::compare(&metaphone, "knight", "night").unwrap());
+    assert!(PhoneticEncoder::compare(&metaphone, "", "night").is_err());
+}
+
+#[test]
+fn test_max_code_length() {
+    assert_eq!(Metaphone::new().max_code_length(), None);
+    assert_eq!(Nysiis::new().max_code_length(), Some(6));
+}
+
+fn generic_encode<E: PhoneticEncoder>(encoder: &E, val: &str) -> String {
+    encoder.encode(val).unwrap()
+}
+
+#[test]
+fn test_generic_usage() {
+    assert_eq!(generic_encode(&Nysiis::new(), "Knight"), "NAGT");
+    assert_eq!(generic_encode(&Metaphone::new(), "phone"), "fn");
+}
+
+#[test]
+fn test_encoder_by_name() {
+    for name in ENCODER_NAMES {
+        let encoder = encoder_by_name(name).unwrap();
+        assert_eq!(encoder.name(), *name);
+    }
+
+    assert_eq!(
+        encoder_by_name("nysiis").unwrap().encode("Smith").unwrap(),
+        "SNAT"
+    );
+    assert!(encoder_by_name("soundx").is_none());
+}
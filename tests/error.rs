@@ -0,0 +1,62 @@
+use phonetics::{Metaphone, Nysiis, PhoneticEncoder, PhoneticError, metaphone::metaphone_metric};
+
+#[test]
+fn test_metaphone_errors() {
+    let m = Metaphone::new();
+    assert_eq!(m.encode(""), Err(PhoneticError::Empty));
+    assert_eq!(
+        m.encode("O'Brien"),
+        Err(PhoneticError::NonAlphabetic {
+            position: 1,
+            char: '\''
+        })
+    );
+    assert_eq!(
+        m.encode("Щербаков"),
+        Err(PhoneticError::UnsupportedScript {
+            position: 0,
+            char: 'Щ'
+        })
+    );
+    assert_eq!(
+        metaphone_metric("smith", "sm1th"),
+        Err(PhoneticError::NonAlphabetic {
+            position: 2,
+            char: '1'
+        })
+    );
+}
+
+#[test]
+fn test_nysiis_errors() {
+    let n = Nysiis::new();
+    assert_eq!(n.try_encode(""), Err(PhoneticError::Empty));
+    assert_eq!(
+        n.try_encode("123"),
+        Err(PhoneticError::NonAlphabetic {
+            position: 0,
+            char: '1'
+        })
+    );
+    assert_eq!(
+        PhoneticEncoder::encode(&n, "Иван"),
+        Err(PhoneticError::UnsupportedScript {
+            position: 0,
+            char: 'И'
+        })
+    );
+    assert_eq!(n.try_encode("O'Brien"), Ok("OBRAN".to_string()));
+}
+
+#[test]
+fn test_error_display() {
+    let err: Box<dyn std::error::Error> = Box::new(PhoneticError::NonAlphabetic {
+        position: 3,
+        char: '-',
+    });
+    assert_eq!(
+        err.to_string(),
+        "non-alphabetic character '-' at position 3"
+    );
+    assert_eq!(PhoneticError::Empty.to_string(), "input is empty");
+}
@@ -0,0 +1,71 @@
+use phonetics::{Eudex, PhoneticEncoder, PhoneticError};
+
+fn hash(val: &str) -> u64 {
+    Eudex::new().hash(val).unwrap()
+}
+
+#[test]
+fn test_equal_hashes() {
+    assert_eq!(hash("JAva"), hash("jAva"));
+    assert_eq!(hash("co!mputer"), hash("computer"));
+    assert_eq!(hash("comp@u#te?r"), hash("computer"));
+    assert_eq!(hash("lal"), hash("lel"));
+    assert_eq!(hash("rindom"), hash("ryndom"));
+    assert_eq!(hash("riiiindom"), hash("ryyyyyndom"));
+    assert_eq!(hash("hello"), hash("hellou"));
+}
+
+#[test]
+fn test_different_hashes() {
+    assert_ne!(hash("reddit"), hash("eddit"));
+    assert_ne!(hash("lol"), hash("lulz"));
+    assert_ne!(hash("ijava"), hash("java"));
+    assert_ne!(hash("jesus"), hash("iesus"));
+}
+
+#[test]
+fn test_hash_layout() {
+    // "j" fills the top byte; "a" adds nothing, "v" and "a" one phone each.
+    assert_eq!(hash("java"), 0x0300_0000_0000_4500);
+    assert_eq!(Eudex::new().encode("java").unwrap(), "0300000000004500");
+    assert_eq!(Eudex::new().encode("").unwrap_err(), PhoneticError::Empty);
+    assert!(Eudex::new().encode("123").is_err());
+}
+
+#[test]
+fn test_long_names_keep_first_letter() {
+    // Phones past the seventh are dropped rather than spilling into the first letter's byte.
+    assert_eq!(hash("Tkbdfgklmnp") >> 56, hash("T") >> 56);
+    assert_eq!(hash("Tkbdfgklmnp") >> 56, 0x0e);
+    assert_ne!(hash("Tkbdfgklmnp") >> 56, hash("Pkbdfgklmnp") >> 56);
+}
+
+#[test]
+fn test_diacritics() {
+    assert_eq!(hash("Éclair"), hash("Eclair"));
+    assert_eq!(hash("Müller"), hash("Muller"));
+}
+
+#[test]
+fn test_distance() {
+    let d = |a, b| Eudex::distance(hash(a), hash(b));
+
+    assert_eq!(d("Smith", "Smith"), 0);
+    assert!(d("Smith", "Smyth") < d("Smith", "Jones"));
+    assert!(d("jumpo", "jumbo") < d("jumpo", "tumbo"));
+    assert!(d("lizzard", "wizzard") > d("rick", "rolled"));
+    assert!(Eudex::hamming(hash("jumpo"), hash("jumbo")) <= d("jumpo", "jumbo"));
+    assert_eq!(Eudex::distance(0, u64::MAX), 255 * 8);
+}
+
+#[test]
+fn test_trait() {
+    let eudex = Eudex::new();
+    assert_eq!(eudex.name(), "eudex");
+    assert_eq!(eudex.max_code_length(), Some(16));
+
+    assert!(PhoneticEncoder::compare(&eudex, "Smith", "Smyth").unwrap());
+    assert!(!PhoneticEncoder::compare(&eudex, "Smith", "Jones").unwrap());
+    assert_eq!(eudex.similarity("Smith", "Smith").unwrap(), 1.0);
+    assert!(eudex.similarity("Smith", "Jones").unwrap() < 1.0);
+}
@@ -0,0 +1,109 @@
+use phonetics::{ExplainStage, Metaphone, Nysiis, NysiisVariant};
+
+#[test]
+fn test_nysiis_explained() {
+    let explanation = Nysiis::new().encode_explained("Ghosh");
+    assert_eq!(explanation.code, "G");
+
+    let rules: Vec<_> = explanation
+        .steps
+        .iter()
+        .map(|step| (step.stage, step.rule))
+        .collect();
+    assert_eq!(
+        rules,
+        vec![
+            (ExplainStage::FirstCharacters, "GH"),
+            (ExplainStage::Transcode, "vowel"),
+            (ExplainStage::Transcode, "SH"),
+            (ExplainStage::Transcode, "H not between vowels"),
+            (ExplainStage::TrailingSuffix, "trailing S"),
+            (ExplainStage::TrailingSuffix, "trailing A"),
+        ]
+    );
+
+    let first = &explanation.steps[0];
+    assert_eq!(first.span, 0..2);
+    assert_eq!(first.emitted, "G");
+    assert_eq!(first.skipped, "H");
+}
+
+#[test]
+fn test_nysiis_first_character_skips() {
+    let explanation = Nysiis::new().encode_explained("Nwachukwu");
+    assert_eq!(explanation.code, "WACACW");
+
+    let first = &explanation.steps[0];
+    assert_eq!(first.rule, "NW");
+    assert_eq!(first.skipped, "N");
+
+    let last = explanation.steps.last().unwrap();
+    assert_eq!(last.stage, ExplainStage::TrailingSuffix);
+    assert_eq!(last.rule, "trailing A");
+}
+
+#[test]
+fn test_explained_code_matches_encode() {
+    for variant in [
+        NysiisVariant::Original,
+        NysiisVariant::Modified,
+        NysiisVariant::Extended,
+    ] {
+        let nysiis = Nysiis::new().with_variant(variant);
+        for name in ["MacDowell", "Schmidt", "Carraway", "Alexander", "O'Brien"] {
+            assert_eq!(nysiis.encode_explained(name).code, nysiis.encode(name));
+        }
+    }
+
+    let metaphone = Metaphone::new();
+    for name in ["Knight", "Thompson", "Schmidt", "Xavier", "Aeschylus"] {
+        assert_eq!(
+            metaphone.encode_explained(name).unwrap().code,
+            metaphone.encode(name).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_nysiis_truncation_and_preprocess() {
+    let explanation = Nysiis::new().encode_explained("O'Alexander");
+    assert_eq!(explanation.steps[0].stage, ExplainStage::Preprocess);
+    assert_eq!(explanation.steps[0].skipped, "'");
+
+    let truncate = explanation.steps.last().unwrap();
+    assert_eq!(truncate.stage, ExplainStage::Truncate);
+    assert_eq!(truncate.span.start, 6);
+}
+
+#[test]
+fn test_metaphone_explained() {
+    let explanation = Metaphone::new().encode_explained("Knight").unwrap();
+    assert_eq!(explanation.code, "nht");
+
+    let first = &explanation.steps[0];
+    assert_eq!(first.stage, ExplainStage::FirstCharacters);
+    assert_eq!(first.rule, "initial gn/kn/pn");
+    assert_eq!(first.skipped, "k");
+
+    let silent_g = explanation
+        .steps
+        .iter()
+        .find(|step| step.rule == "gh/gn/gned")
+        .unwrap();
+    assert_eq!(silent_g.span, 2..3);
+    assert_eq!(silent_g.emitted, "");
+
+    let explanation = Metaphone::new().encode_explained("Phillip").unwrap();
+    let repeated = &explanation.steps[0];
+    assert_eq!(repeated.stage, ExplainStage::Preprocess);
+    assert_eq!(
+        (repeated.input.as_str(), repeated.skipped.as_str()),
+        ("ll", "l")
+    );
+
+    let ph = &explanation.steps[1];
+    assert_eq!(
+        (ph.rule, ph.emitted.as_str(), ph.skipped.as_str()),
+        ("ph", "f", "h")
+    );
+}
@@ -0,0 +1,87 @@
+use phonetics::{DaitchMokotoff, Metaphone, PhoneticEncoder, PhoneticError, PhoneticIndex};
+
+#[test]
+fn test_query_default_encoders() {
+    let mut index = PhoneticIndex::new();
+    index.insert(1, "Smith").unwrap();
+    index.insert(2, "Smyth").unwrap();
+    index.insert(3, "Jones").unwrap();
+
+    let hits = index.query("Smith").unwrap();
+    let ids: Vec<u64> = hits.iter().map(|hit| hit.id).collect();
+    assert_eq!(ids, vec![1, 2]);
+
+    let algorithms: Vec<_> = hits[0].matches.iter().map(|m| m.algorithm).collect();
+    assert_eq!(algorithms, vec!["metaphone", "nysiis"]);
+    assert_eq!(hits[0].matches[1].code, "SNAT");
+}
+
+#[test]
+fn test_hits_ranked_by_matching_encoders() {
+    let mut index = PhoneticIndex::new();
+    index.insert("a", "Catherine").unwrap();
+    index.insert("b", "Kathryn").unwrap();
+
+    // Metaphone codes both as "k0rn"; NYSIIS only matches "Kathryn".
+    let hits = index.query("Kathryn").unwrap();
+    assert_eq!(hits[0].id, "b");
+    assert_eq!(hits[0].matches.len(), 2);
+    assert_eq!(hits[1].id, "a");
+    assert_eq!(hits[1].matches.len(), 1);
+    assert_eq!(hits[1].matches[0].algorithm, "metaphone");
+}
+
+#[test]
+fn test_remove() {
+    let mut index = PhoneticIndex::new();
+    index.insert(1, "Smith").unwrap();
+    index.insert(1, "Schmidt").unwrap();
+    index.insert(2, "Smith").unwrap();
+    assert_eq!(index.len(), 2);
+
+    assert!(index.remove(&1));
+    assert!(!index.remove(&1));
+    assert!(!index.contains(&1));
+
+    let ids: Vec<u64> = index.query("Smith").unwrap().iter().map(|h| h.id).collect();
+    assert_eq!(ids, vec![2]);
+    assert!(index.query("Schmidt").unwrap().is_empty());
+}
+
+#[test]
+fn test_many_ids_under_one_code() {
+    let mut index = PhoneticIndex::new();
+    for id in 0..5_000 {
+        index.insert(id, "Smith").unwrap();
+    }
+    index.insert(7, "Smyth").unwrap();
+    for id in (0..5_000).step_by(2) {
+        assert!(index.remove(&id));
+    }
+
+    let hits = index.query("Smith").unwrap();
+    assert_eq!(hits.len(), 2_500);
+    assert_eq!(hits[0].id, 1);
+}
+
+#[test]
+fn test_custom_and_multi_code_encoders() {
+    let encoders: Vec<Box<dyn PhoneticEncoder>> =
+        vec![Box::new(Metaphone::new()), Box::new(DaitchMokotoff::new())];
+    let mut index = PhoneticIndex::with_encoders(encoders);
+
+    // "Szlachter" has two Daitch–Mokotoff codes; either one finds it.
+    index.insert(7, "Szlachter").unwrap();
+    let hits = index.query("Slachter").unwrap();
+    assert_eq!(hits.len(), 1);
+    let algorithms: Vec<_> = hits[0].matches.iter().map(|m| m.algorithm).collect();
+    assert_eq!(algorithms, vec!["daitch_mokotoff"]);
+}
+
+#[test]
+fn test_rejected_names() {
+    let mut index: PhoneticIndex = PhoneticIndex::new();
+    assert_eq!(index.insert(1, ""), Err(PhoneticError::Empty));
+    assert!(index.is_empty());
+    assert!(index.query("Sm1th").is_err());
+}
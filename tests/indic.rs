@@ -0,0 +1,109 @@
+use phonetics::{IndicPhonetic, IndicScript, PhoneticEncoder, PhoneticError, encoder_by_name};
+
+fn key(val: &str) -> String {
+    IndicPhonetic::new().encode(val).unwrap()
+}
+
+#[test]
+fn test_romanizations_match_devanagari() {
+    for name in ["Shrivastava", "Srivastav", "श्रीवास्तव", "Śrīvāstava"] {
+        assert_eq!(key(name), "SRIVASTAV", "{name}");
+    }
+    for name in ["Krishna", "कृष्ण", "Kr̥ṣṇa", "Kṛṣṇa"] {
+        assert_eq!(key(name), "KRISN", "{name}");
+    }
+    for name in ["Lakshmi", "Laxmi", "लक्ष्मी"] {
+        assert_eq!(key(name), "LAKSMI", "{name}");
+    }
+    assert_eq!(key("Gyan"), key("ज्ञान"));
+    assert_eq!(key("Jñāna"), key("ज्ञान"));
+}
+
+#[test]
+fn test_consonant_classes() {
+    // Aspirates and retroflexes fold into plain dentals, "w" into "v".
+    assert_eq!(key("Bhatt"), "BAT");
+    assert_eq!(key("भट्ट"), "BAT");
+    assert_eq!(key("Dwivedi"), key("द्विवेदी"));
+    assert_eq!(key("Chhabra"), key("छाबड़ा"));
+    assert_eq!(key("Fatehpur"), key("फ़तेहपुर"));
+    // The anusvara and a written "m" before a stop are the same nasal.
+    assert_eq!(key("Ambika"), key("अंबिका"));
+    assert_eq!(key("Sharma"), key("शर्मा"));
+}
+
+#[test]
+fn test_schwa_deletion() {
+    for name in ["Kamala", "Kamla", "कमला", "Kamalā"] {
+        assert_eq!(key(name), "KAML", "{name}");
+    }
+    assert_eq!(key("Vijay"), key("विजय"));
+    // ISO 15919 tells the inherent final vowel from a long one.
+    assert_eq!(key("Yādava"), key("यादव"));
+    assert_eq!(key("Yadav"), "YADAV");
+    assert_eq!(key("Deepak"), key("दीपक"));
+}
+
+#[test]
+fn test_other_scripts() {
+    let encoder = IndicPhonetic::new()
+        .with_script(IndicScript::Bengali)
+        .with_script(IndicScript::Gurmukhi)
+        .with_script(IndicScript::Telugu);
+
+    assert!(encoder.compare("চট্টোপাধ্যায়", "Chattopadhyay").unwrap());
+    assert!(encoder.compare("ਸਿੰਘ", "Singh").unwrap());
+    assert!(encoder.compare("వెంకటేశ్వర", "Venkateshwara").unwrap());
+    assert_eq!(
+        encoder.config(),
+        "scripts=devanagari+bengali+gurmukhi+telugu"
+    );
+
+    assert_eq!(
+        IndicPhonetic::new().encode("ਸਿੰਘ").unwrap_err(),
+        PhoneticError::UnsupportedScript {
+            position: 0,
+            char: 'ਸ'
+        }
+    );
+}
+
+#[test]
+fn test_errors_and_registry() {
+    let encoder = IndicPhonetic::new();
+    assert_eq!(encoder.encode("").unwrap_err(), PhoneticError::Empty);
+    assert_eq!(
+        encoder.encode("श्री 1").unwrap_err(),
+        PhoneticError::NonAlphabetic {
+            position: 4,
+            char: ' '
+        }
+    );
+    assert_eq!(
+        encoder.encode("्").unwrap_err(),
+        PhoneticError::NonAlphabetic {
+            position: 0,
+            char: '्'
+        }
+    );
+    // One value is read in one script.
+    assert_eq!(
+        encoder.encode("Ramराम").unwrap_err(),
+        PhoneticError::UnsupportedScript {
+            position: 3,
+            char: 'र'
+        }
+    );
+    assert_eq!(
+        encoder.encode("रामRam").unwrap_err(),
+        PhoneticError::UnsupportedScript {
+            position: 3,
+            char: 'R'
+        }
+    );
+
+    let encoder = encoder_by_name("indic").unwrap();
+    assert_eq!(encoder.encode("श्रीवास्तव").unwrap(), "SRIVASTAV");
+    assert_eq!(encoder.config(), "scripts=devanagari");
+    assert_eq!(encoder.max_code_length(), None);
+}
@@ -0,0 +1,82 @@
+use phonetics::{MatchRating, PhoneticEncoder, PhoneticError};
+
+#[test]
+fn test_match_rating_codex() {
+    let mra = MatchRating::new();
+    assert_eq!(mra.encode("Byrne").unwrap(), "BYRN");
+    assert_eq!(mra.encode("Boern").unwrap(), "BRN");
+    assert_eq!(mra.encode("Smith").unwrap(), "SMTH");
+    assert_eq!(mra.encode("Smyth").unwrap(), "SMYTH");
+    assert_eq!(mra.encode("Catherine").unwrap(), "CTHRN");
+    assert_eq!(mra.encode("Kathryn").unwrap(), "KTHRYN");
+    assert_eq!(mra.encode("Aaron").unwrap(), "ARN");
+}
+
+#[test]
+fn test_codex_is_cut_to_first_and_last_three() {
+    let mra = MatchRating::new();
+    assert_eq!(mra.encode("Schwarzkopf").unwrap(), "SCHKPF");
+    assert_eq!(mra.encode("Hammond").unwrap(), "HMND");
+}
+
+#[test]
+fn test_graded_comparison() {
+    let mra = MatchRating::new();
+
+    let result = mra.compare("Byrne", "Boern").unwrap();
+    assert_eq!(result.similarity, 5);
+    assert_eq!(result.minimum_rating, 4);
+    assert!(result.is_match());
+
+    let result = mra.compare("Smith", "Smyth").unwrap();
+    assert_eq!(result.similarity, 5);
+    assert_eq!(result.minimum_rating, 3);
+    assert!(result.is_match());
+
+    let result = mra.compare("Catherine", "Kathryn").unwrap();
+    assert_eq!(result.minimum_rating, 3);
+    assert!(result.is_match());
+}
+
+#[test]
+fn test_non_matching_names() {
+    let mra = MatchRating::new();
+
+    let result = mra.compare("Franklin", "Lee").unwrap();
+    assert_eq!(result.similarity, 0);
+    assert!(!result.is_match());
+
+    assert!(!mra.compare("Smith", "Jones").unwrap().is_match());
+}
+
+#[test]
+fn test_right_to_left_pass_uses_leftover_characters() {
+    let mra = MatchRating::new();
+    // "R" matches left to right and cannot match again from the end.
+    let result = mra.compare("KR", "MRTR").unwrap();
+    assert_eq!(result.similarity, 3);
+}
+
+#[test]
+fn test_diacritics() {
+    let mra = MatchRating::new();
+    assert_eq!(mra.encode("Élise").unwrap(), "ELS");
+    assert_eq!(
+        mra.encode("ƿ"),
+        Err(PhoneticError::UnsupportedScript {
+            position: 0,
+            char: 'ƿ'
+        })
+    );
+}
+
+#[test]
+fn test_match_rating_encoder_trait() {
+    let mra = MatchRating::new();
+    let encoder: &dyn PhoneticEncoder = &mra;
+
+    assert_eq!(encoder.name(), "match_rating");
+    assert_eq!(encoder.max_code_length(), Some(6));
+    assert!(encoder.compare("Byrne", "Boern").unwrap());
+    assert_eq!(encoder.encode(""), Err(PhoneticError::Empty));
+}
@@ -16,10 +16,10 @@ fn test_metaphone() {
 #[test]
 fn test_metaphone_compare() {
     let m = Metaphone::new();
-    assert!(m.compare("smith", "Schmidt").unwrap() == false);
-    assert!(m.compare("johnson", "Johnston").unwrap() == false);
-    assert!(m.compare("white", "wight").unwrap() == false);
-    assert!(m.compare("knight", "night").unwrap() == true);
+    assert!(!m.compare("smith", "Schmidt").unwrap());
+    assert!(!m.compare("johnson", "Johnston").unwrap());
+    assert!(!m.compare("white", "wight").unwrap());
+    assert!(m.compare("knight", "night").unwrap());
 }
 
 #[test]
@@ -29,8 +29,8 @@ fn test_function_api() {
     assert_eq!(metaphone("psychology").unwrap(), "psxlj");
     assert_eq!(metaphone("beautiful").unwrap(), "btfl");
 
-    assert!(metaphone_metric("white", "wight").unwrap() == false);
-    assert!(metaphone_metric("knight", "night").unwrap() == true);
+    assert!(!metaphone_metric("white", "wight").unwrap());
+    assert!(metaphone_metric("knight", "night").unwrap());
 }
 
 #[test]
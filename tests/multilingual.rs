@@ -0,0 +1,122 @@
+use phonetics::{Language, MultilingualPhonetic, NameType, PhoneticError};
+
+#[test]
+fn test_language_guessing() {
+    let encoder = MultilingualPhonetic::new();
+    assert_eq!(encoder.guess_languages("Schwarz"), vec![Language::German]);
+    assert_eq!(
+        encoder.guess_languages("Shcherbakov"),
+        vec![Language::Russian]
+    );
+    assert_eq!(encoder.guess_languages("Nagy"), vec![Language::Hungarian]);
+    assert_eq!(encoder.guess_languages("Popescu"), vec![Language::Romanian]);
+    assert_eq!(
+        encoder.guess_languages("Szwarc"),
+        vec![Language::Hungarian, Language::Polish]
+    );
+    assert_eq!(encoder.guess_languages("Garcia").len(), 10);
+}
+
+#[test]
+fn test_name_type_languages() {
+    let encoder = MultilingualPhonetic::new().with_name_type(NameType::Sephardic);
+    assert_eq!(encoder.guess_languages("Shcherbakov"), vec![]);
+    assert_eq!(
+        encoder.guess_languages("Garcia"),
+        vec![
+            Language::French,
+            Language::Italian,
+            Language::Portuguese,
+            Language::Spanish
+        ]
+    );
+}
+
+#[test]
+fn test_encode() {
+    let encoder = MultilingualPhonetic::new();
+    assert_eq!(encoder.encode("Schwarz").unwrap(), vec!["Svarts"]);
+    assert_eq!(encoder.encode("Shcherbakov").unwrap(), vec!["StSerbakof"]);
+    assert_eq!(encoder.encode("Müller").unwrap(), vec!["miler", "muler"]);
+    assert_eq!(encoder.encode("Popescu").unwrap(), vec!["popesku"]);
+}
+
+#[test]
+fn test_name_particles() {
+    let encoder = MultilingualPhonetic::new();
+    assert_eq!(
+        encoder.encode("d'Artagnan").unwrap(),
+        vec!["artagnan", "artanjan", "dartanjan"]
+    );
+
+    let encoder = MultilingualPhonetic::new().with_name_type(NameType::Ashkenazi);
+    assert_eq!(encoder.encode("ben Schwarz").unwrap(), vec!["Svarts"]);
+}
+
+#[test]
+fn test_name_type_rules() {
+    let generic = MultilingualPhonetic::new();
+    let ashkenazi = MultilingualPhonetic::new().with_name_type(NameType::Ashkenazi);
+    let sephardic = MultilingualPhonetic::new().with_name_type(NameType::Sephardic);
+
+    assert_eq!(ashkenazi.encode("Goldshtein").unwrap(), vec!["goldStajn"]);
+    assert!(ashkenazi.compare("Goldstein", "Goldshtein").unwrap());
+    assert_eq!(ashkenazi.encode("Khaim").unwrap(), vec!["xaim"]);
+    assert_eq!(generic.encode("Khaim").unwrap(), vec!["kaim", "xaim"]);
+
+    // "b" and "v" are one sound in Spanish and Portuguese names.
+    assert_eq!(sephardic.encode("Bidal").unwrap(), vec!["bidal", "vidal"]);
+    assert_eq!(generic.encode("Bidal").unwrap(), vec!["bidal"]);
+    assert!(sephardic.compare("Ximenes", "Shimenes").unwrap());
+}
+
+#[test]
+fn test_encode_with_languages() {
+    let encoder = MultilingualPhonetic::new();
+    assert_eq!(
+        encoder
+            .encode_with_languages("Kowalski", &[Language::Polish])
+            .unwrap(),
+        vec!["kovalski"]
+    );
+    assert_eq!(
+        encoder.encode_with_languages("Kowalski", &[]).unwrap(),
+        encoder.encode("Kowalski").unwrap()
+    );
+}
+
+#[test]
+fn test_too_many_readings() {
+    let encoder = MultilingualPhonetic::new();
+    // Every "v" and "ü" has two German readings: 2^10 readings are kept, 2^11 are not.
+    assert_eq!(
+        encoder
+            .encode_with_languages("vüvüvüvüvü", &[Language::German])
+            .unwrap()
+            .len(),
+        1024
+    );
+    assert_eq!(
+        encoder.encode_with_languages("vüvüvüvüvüv", &[Language::German]),
+        Err(PhoneticError::TooManyReadings { limit: 1024 })
+    );
+}
+
+#[test]
+fn test_compare() {
+    let encoder = MultilingualPhonetic::new();
+    assert!(encoder.compare("Schwarz", "Shvarts").unwrap());
+    assert!(encoder.compare("Schwarz", "Szwarc").unwrap());
+    assert!(encoder.compare("Müller", "Mueller").unwrap());
+    assert!(encoder.compare("Moskowitz", "Moskovitz").unwrap());
+    assert!(encoder.compare("Kowalski", "Kovalsky").unwrap());
+    assert!(!encoder.compare("Schwarz", "Kowalski").unwrap());
+}
+
+#[test]
+fn test_error_handling() {
+    let encoder = MultilingualPhonetic::new();
+    assert_eq!(encoder.encode("  "), Err(PhoneticError::Empty));
+    assert!(encoder.encode("Smith2").is_err());
+    assert!(encoder.encode("Иван").is_err());
+}
@@ -0,0 +1,107 @@
+use phonetics::{Metaphone, NameEncoder, Nysiis, PhoneticEncoder, PhoneticError, TokenMode};
+
+#[test]
+fn test_multi_word_names() {
+    let encoder = NameEncoder::new(Box::new(Metaphone::new()));
+    assert!(Metaphone::new().encode("Mary Ann").is_err());
+
+    let name = encoder.encode_name("Mary Ann").unwrap();
+    assert_eq!(name.code, "mr an");
+    let texts: Vec<&str> = name.tokens.iter().map(|t| t.text.as_str()).collect();
+    assert_eq!(texts, vec!["Mary", "Ann"]);
+    assert_eq!(name.tokens[1].span, 5..8);
+    assert_eq!(name.tokens[1].code, "an");
+}
+
+#[test]
+fn test_separators() {
+    let encoder = NameEncoder::new(Box::new(Nysiis::new()));
+
+    let name = encoder.encode_name("  O'Brien-Smith  ").unwrap();
+    assert_eq!(name.code, "O BRAN SNAT");
+    assert_eq!(name.tokens[0].span, 2..3);
+
+    assert_eq!(
+        encoder.encode_name("O\u{2019}Brien").unwrap().code,
+        "O BRAN"
+    );
+    assert_eq!(encoder.encode_name("De La Cruz").unwrap().code, "D L CR");
+}
+
+#[test]
+fn test_joined_mode() {
+    let encoder = NameEncoder::new(Box::new(Nysiis::new())).with_mode(TokenMode::Joined);
+
+    let name = encoder.encode_name("De La Cruz").unwrap();
+    assert_eq!(name.code, "DALACR");
+    assert_eq!(name.code, encoder.encode_name("DeLaCruz").unwrap().code);
+    assert_eq!(name.tokens.len(), 3);
+}
+
+#[test]
+fn test_errors() {
+    let encoder = NameEncoder::new(Box::new(Metaphone::new()));
+
+    assert_eq!(
+        encoder.encode_name(" - ").unwrap_err(),
+        PhoneticError::Empty
+    );
+    assert_eq!(
+        encoder.encode_name("Mary An3").unwrap_err(),
+        PhoneticError::NonAlphabetic {
+            position: 7,
+            char: '3'
+        }
+    );
+}
+
+/// Accepts words of up to five letters, as if it had an input limit.
+struct ShortWords;
+
+impl PhoneticEncoder for ShortWords {
+    fn encode(&self, val: &str) -> Result<String, PhoneticError> {
+        match val.chars().nth(5) {
+            Some(c) => Err(PhoneticError::NonAlphabetic {
+                position: 5,
+                char: c,
+            }),
+            None => Ok(val.to_uppercase()),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "short_words"
+    }
+
+    fn max_code_length(&self) -> Option<usize> {
+        Some(5)
+    }
+}
+
+#[test]
+fn test_joined_error_positions() {
+    // The joined "MaryAnn" fails at its sixth letter, the first "n" of "Ann" in the name.
+    let encoder = NameEncoder::new(Box::new(ShortWords)).with_mode(TokenMode::Joined);
+    assert_eq!(
+        encoder.encode_name("Mary Ann").unwrap_err(),
+        PhoneticError::NonAlphabetic {
+            position: 6,
+            char: 'n'
+        }
+    );
+}
+
+#[test]
+fn test_trait() {
+    let encoder = NameEncoder::new(Box::new(Nysiis::new()));
+    assert_eq!(encoder.name(), "nysiis");
+    assert_eq!(
+        encoder.config(),
+        "variant=extended,max_length=6,tokens=separate"
+    );
+    assert!(PhoneticEncoder::compare(&encoder, "Mary-Ann", "Mary Ann").unwrap());
+    assert_eq!(
+        PhoneticEncoder::encode(&encoder, "Mary Ann").unwrap(),
+        "MARY AN"
+    );
+}
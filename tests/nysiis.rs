@@ -1,4 +1,4 @@
-use phonetics::Nysiis;
+use phonetics::{Nysiis, NysiisVariant, PhoneticEncoder};
 
 #[test]
 fn test_empty_string() {
@@ -57,3 +57,45 @@ fn test_special_cases() {
     assert_eq!(nysiis.encode("Jharkhand"), "JARCKA");
     assert_eq!(nysiis.encode("Khan"), "CAN");
 }
+
+#[test]
+fn test_original_variant() {
+    let nysiis = Nysiis::new().with_variant(NysiisVariant::Original);
+    assert_eq!(nysiis.encode("Brian"), "BRAN");
+    assert_eq!(nysiis.encode("Brown"), "BRAN");
+    assert_eq!(nysiis.encode("Kipp"), "CAP");
+    assert_eq!(nysiis.encode("Dent"), "DAD");
+    assert_eq!(nysiis.encode("Schmit"), "SNAT");
+    assert_eq!(nysiis.encode("Schmidt"), "SNAD");
+    assert_eq!(nysiis.encode("Truman"), "TRANAN");
+    // None of the extended transcriptions apply.
+    assert_eq!(nysiis.encode("Tsonga"), "TSANG");
+    assert_eq!(nysiis.encode("Dodgson"), "DADGSA");
+}
+
+#[test]
+fn test_modified_variant() {
+    let nysiis = Nysiis::new()
+        .with_variant(NysiisVariant::Modified)
+        .with_max_length(None);
+    assert_eq!(nysiis.encode("Brown"), "BRAN");
+    assert_eq!(nysiis.encode("Edwards"), "EDWARD");
+    assert_eq!(nysiis.encode("Dodgson"), "DAGSAN");
+    assert_eq!(nysiis.encode("Carraway"), "CARAY");
+    assert_eq!(nysiis.encode("Knight"), "KNAGT");
+    assert_eq!(nysiis.encode("MacIntosh"), "MCANTAS");
+}
+
+#[test]
+fn test_max_length() {
+    let nysiis = Nysiis::new();
+    assert_eq!(nysiis.encode("Alexander"), "ALAXAN");
+    assert_eq!(nysiis.max_code_length(), Some(6));
+
+    let nysiis = Nysiis::new().with_max_length(None);
+    assert_eq!(nysiis.encode("Alexander"), "ALAXANDAR");
+    assert_eq!(nysiis.max_code_length(), None);
+
+    let nysiis = Nysiis::new().with_max_length(Some(4));
+    assert_eq!(nysiis.encode("Alexander"), "ALAX");
+}
@@ -0,0 +1,93 @@
+use phonetics::{
+    Metaphone, NameEncoder, Nysiis, Particle, ParticleHandling, ParticleNormalizer,
+    PhoneticEncoder, TokenMode,
+};
+
+#[test]
+fn test_mac_spellings_match() {
+    let normalizer = ParticleNormalizer::new();
+    for name in ["McDonald", "MacDonald", "Mac Donald", "Mc Donald"] {
+        assert_eq!(normalizer.normalize(name).name, "MacDonald", "{}", name);
+    }
+
+    let encoder = NameEncoder::new(Box::new(Nysiis::new())).with_normalizer(normalizer);
+    assert!(PhoneticEncoder::compare(&encoder, "McDonald", "Mac Donald").unwrap());
+    let encoder = NameEncoder::new(Box::new(Metaphone::new()))
+        .with_mode(TokenMode::Joined)
+        .with_normalizer(ParticleNormalizer::new());
+    assert!(Metaphone::new().encode("Mac Donald").is_err());
+    assert!(PhoneticEncoder::compare(&encoder, "Mc Donald", "MacDonald").unwrap());
+}
+
+#[test]
+fn test_canonicalize_reports_particles() {
+    let normalized = ParticleNormalizer::new().normalize("Ludwig van der Berg");
+    assert_eq!(normalized.name, "Ludwig vanderBerg");
+    assert_eq!(
+        normalized.particles,
+        vec![
+            Particle {
+                text: "van".into(),
+                span: 7..10,
+                replacement: Some("van".into()),
+            },
+            Particle {
+                text: "der".into(),
+                span: 11..14,
+                replacement: Some("der".into()),
+            },
+        ]
+    );
+
+    let normalized = ParticleNormalizer::new().normalize("Mcgregor");
+    assert_eq!(normalized.name, "Macgregor");
+    assert_eq!(normalized.particles[0].text, "Mc");
+    assert_eq!(normalized.particles[0].replacement.as_deref(), Some("Mac"));
+}
+
+#[test]
+fn test_strip() {
+    let normalizer = ParticleNormalizer::new().with_handling(ParticleHandling::Strip);
+
+    assert_eq!(normalizer.normalize("O'Brien").name, "Brien");
+    assert_eq!(normalizer.normalize("Al-Rashid").name, "Rashid");
+    assert_eq!(normalizer.normalize("Maria De La Cruz").name, "Maria Cruz");
+    assert_eq!(normalizer.normalize("FitzGerald").name, "Gerald");
+
+    let normalized = normalizer.normalize("McDonald");
+    assert_eq!(normalized.name, "Donald");
+    assert_eq!(normalized.particles[0].span, 0..2);
+    assert_eq!(normalized.particles[0].replacement, None);
+}
+
+#[test]
+fn test_not_particles() {
+    let normalizer = ParticleNormalizer::new().with_handling(ParticleHandling::Strip);
+
+    // Particles must precede another word, and "Mac" a capital.
+    assert_eq!(normalizer.normalize("Chris De").name, "Chris De");
+    assert_eq!(normalizer.normalize("Macy").name, "Macy");
+    assert_eq!(normalizer.normalize("Mack").name, "Mack");
+    for name in ["MACK", "MACY", "MACE", "MACDONALD"] {
+        assert_eq!(normalizer.normalize(name).name, name);
+    }
+    assert_eq!(normalizer.normalize("MCDONALD").name, "DONALD");
+    assert!(normalizer.normalize("Smith").particles.is_empty());
+}
+
+#[test]
+fn test_custom_particles_with_encoder() {
+    let normalizer = ParticleNormalizer::new()
+        .with_handling(ParticleHandling::Strip)
+        .with_particles(["BIN"]);
+    assert_eq!(normalizer.normalize("Omar bin Laden").name, "Omar Laden");
+    assert_eq!(normalizer.normalize("De Niro").name, "De Niro");
+
+    let encoder = NameEncoder::new(Box::new(Metaphone::new()))
+        .with_mode(TokenMode::Joined)
+        .with_normalizer(ParticleNormalizer::new().with_handling(ParticleHandling::Strip));
+    let name = encoder.encode_name("van Dyke").unwrap();
+    assert_eq!(name.code, Metaphone::new().encode("Dyke").unwrap());
+    assert_eq!(name.particles.len(), 1);
+    assert_eq!(encoder.config(), "tokens=joined,particles=strip");
+}
@@ -0,0 +1,131 @@
+use phonetics::{
+    DaitchMokotoff, IndexFormatError, Metaphone, Nysiis, PersistedIndex, PhoneticEncoder,
+    PhoneticIndex,
+};
+
+const NAMES: [&str; 6] = ["Smith", "Smyth", "Schmidt", "Catherine", "Kathryn", "Jones"];
+
+fn encoders() -> Vec<Box<dyn PhoneticEncoder>> {
+    vec![
+        Box::new(Metaphone::new()),
+        Box::new(Nysiis::new()),
+        Box::new(DaitchMokotoff::new()),
+    ]
+}
+
+fn build() -> (PhoneticIndex, Vec<u8>) {
+    let mut index = PhoneticIndex::with_encoders(encoders());
+    for (id, name) in NAMES.iter().enumerate().rev() {
+        index.insert(id as u64 * 10, name).unwrap();
+    }
+    index.insert(50, "Smithe").unwrap();
+
+    let mut bytes = Vec::new();
+    index.write_to(&mut bytes).unwrap();
+    (index, bytes)
+}
+
+#[test]
+fn test_round_trip_queries() {
+    let (index, bytes) = build();
+    let persisted = PersistedIndex::open(&bytes[..], encoders()).unwrap();
+    persisted.verify().unwrap();
+
+    assert_eq!(persisted.len(), index.len());
+    for name in NAMES.iter().chain(&["Smithson", "Kathy", "Zed"]) {
+        assert_eq!(persisted.query(name).unwrap(), index.query(name).unwrap());
+    }
+    assert!(persisted.query("Sm1th").is_err());
+}
+
+#[test]
+fn test_encoder_config_mismatch() {
+    let (_, bytes) = build();
+    let mut other = encoders();
+    other[1] = Box::new(Nysiis::new().with_max_length(None));
+
+    match PersistedIndex::open(&bytes[..], other) {
+        Err(IndexFormatError::EncoderMismatch { expected, found }) => {
+            assert_eq!(expected[1], "nysiis(variant=extended,max_length=6)");
+            assert_eq!(found[1], "nysiis(variant=extended,max_length=none)");
+        }
+        _ => panic!("expected an encoder mismatch"),
+    }
+
+    let err = PersistedIndex::open(&bytes[..], encoders().into_iter().take(2).collect());
+    assert!(matches!(err, Err(IndexFormatError::EncoderMismatch { .. })));
+}
+
+#[test]
+fn test_corruption_detected() {
+    let (_, mut bytes) = build();
+    let last = bytes.len() - 1;
+    bytes[last] ^= 0xff;
+    assert!(matches!(
+        PersistedIndex::open(&bytes[..], encoders()),
+        Err(IndexFormatError::ChecksumMismatch)
+    ));
+    // Opening unverified does not read the body; verifying does.
+    let persisted = PersistedIndex::open_unverified(&bytes[..], encoders()).unwrap();
+    assert!(matches!(
+        persisted.verify(),
+        Err(IndexFormatError::ChecksumMismatch)
+    ));
+
+    assert!(matches!(
+        PersistedIndex::open(&bytes[..bytes.len() - 3], encoders()),
+        Err(IndexFormatError::Corrupt(_))
+    ));
+}
+
+#[test]
+fn test_damaged_code_table_is_not_read() {
+    let (_, mut bytes) = build();
+
+    // Point the first postings offset of the first encoder past the end of its data.
+    let id_count = u32::from_le_bytes(bytes[24..28].try_into().unwrap()) as usize;
+    let name_len = u16::from_le_bytes(bytes[28 + id_count * 8..][..2].try_into().unwrap());
+    let table = 28 + id_count * 8 + 2 + name_len as usize;
+    let config_len = u16::from_le_bytes(bytes[table..table + 2].try_into().unwrap()) as usize;
+    let table = table + 2 + config_len + 4;
+    bytes[table + 8..table + 12].copy_from_slice(&u32::MAX.to_le_bytes());
+
+    assert!(PersistedIndex::open(&bytes[..], encoders()).is_err());
+    let persisted = PersistedIndex::open_unverified(&bytes[..], encoders()).unwrap();
+    assert!(persisted.verify().is_err());
+    assert!(persisted.query("Smith").is_ok());
+}
+
+#[test]
+fn test_bad_header() {
+    let (_, mut bytes) = build();
+    bytes[4] = 9;
+    assert!(matches!(
+        PersistedIndex::open(&bytes[..], encoders()),
+        Err(IndexFormatError::UnsupportedVersion(9))
+    ));
+
+    assert!(matches!(
+        PersistedIndex::open(&b"name,code\n"[..], encoders()),
+        Err(IndexFormatError::BadMagic)
+    ));
+}
+
+#[test]
+fn test_memory_mapped_file() {
+    let (index, bytes) = build();
+    let path = std::env::temp_dir().join(format!("phonetics-{}.phix", std::process::id()));
+    std::fs::write(&path, &bytes).unwrap();
+
+    let file = std::fs::File::open(&path).unwrap();
+    let map = unsafe { memmap2::Mmap::map(&file).unwrap() };
+    let persisted = PersistedIndex::open_unverified(map, encoders()).unwrap();
+    persisted.verify().unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(
+        persisted.query("Smith").unwrap(),
+        index.query("Smith").unwrap()
+    );
+    assert!(persisted.query("Zed").unwrap().is_empty());
+}
@@ -0,0 +1,159 @@
+use phonetics::{
+    ArabicRomanization, GreekScheme, Metaphone, NameEncoder, Nysiis, PhoneticEncoder,
+    PhoneticError, Transliterator,
+};
+
+/// Greek names with their passport spellings under ELOT 743.
+const GREEK: &[(&str, &str)] = &[
+    ("Παπαδόπουλος", "Papadopoulos"),
+    ("Γεώργιος", "Georgios"),
+    ("Αλέξανδρος", "Alexandros"),
+    ("Κωνσταντίνος", "Konstantinos"),
+    ("Ευάγγελος", "Evangelos"),
+    ("Δημήτριος", "Dimitrios"),
+    ("Χριστόδουλος", "Christodoulos"),
+    ("Θεοδωράκης", "Theodorakis"),
+    ("Μπακογιάννη", "Bakogianni"),
+    ("Ψαρράς", "Psarras"),
+    ("Αυγερινός", "Avgerinos"),
+    ("Ευθυμίου", "Efthymiou"),
+    ("Σταύρος", "Stavros"),
+    ("Τσίπρας", "Tsipras"),
+    ("Ζαχαρίας", "Zacharias"),
+    ("Νικολαΐδης", "Nikolaidis"),
+    ("Αγγελόπουλος", "Angelopoulos"),
+    ("Λαμπράκης", "Lamprakis"),
+    ("ΨΑΡΡΑΣ", "PSARRAS"),
+];
+
+/// Arabic names, mostly written without vowel marks, with a common Latin spelling of each.
+const ARABIC: &[(&str, &str)] = &[
+    ("محمد", "Mohammed"),
+    ("مُحَمَّد", "Muhamad"),
+    ("أحمد", "Ahmad"),
+    ("بشير", "Bashir"),
+    ("فاطمة", "Fatima"),
+    ("زينب", "Zaynab"),
+    ("سلمان", "Salman"),
+    ("سَلْمَان", "Salman"),
+    ("جمال", "Jamal"),
+    ("طارق", "Tariq"),
+    ("الرشيد", "Al-Rashid"),
+    ("الرشيد", "ar-Rashid"),
+    ("الرشيد", "Arrashid"),
+    ("الرَّشِيد", "Ar-Rashid"),
+    ("الشامي", "ash-Shami"),
+    ("الصباح", "as-Sabah"),
+    ("الدين", "ad-Din"),
+    ("الحسن", "Al-Hassan"),
+    ("القاسم", "El-Kassem"),
+    ("نصر", "Nasr"),
+    ("وليد", "Walid"),
+    ("ياسر", "Yasser"),
+    ("منصور", "Mansour"),
+    ("نور", "Noor"),
+    ("يوسف", "Youssef"),
+];
+
+fn romanizer() -> Transliterator {
+    Transliterator::new()
+        .with_greek(GreekScheme::Elot743)
+        .with_arabic(ArabicRomanization::new())
+}
+
+#[test]
+fn test_greek_corpus() {
+    let t = romanizer();
+    for (greek, latin) in GREEK {
+        assert_eq!(t.transliterate(greek), *latin, "{greek}");
+    }
+
+    let nysiis = Nysiis::new().with_transliterator(t);
+    assert_eq!(nysiis.encode("Παπαδόπουλος"), nysiis.encode("Papadopoulos"));
+}
+
+#[test]
+fn test_arabic_corpus() {
+    let metaphone = Metaphone::new().with_transliterator(romanizer());
+    for (arabic, latin) in ARABIC {
+        assert_eq!(
+            metaphone.encode(arabic).unwrap(),
+            metaphone.encode(latin).unwrap(),
+            "{arabic} / {latin}"
+        );
+    }
+}
+
+#[test]
+fn test_arabic_name_variants() {
+    let t = romanizer();
+    assert_eq!(t.transliterate("محمد"), "Muhammad");
+    assert_eq!(t.transliterate("Mohamed"), "Muhammad");
+    assert_eq!(t.transliterate("mohammed"), "muhammad");
+    assert_eq!(t.transliterate("MOHAMED"), "MUHAMMAD");
+    assert_eq!(t.transliterate("Mohamed Salah"), "Muhammad Salah");
+
+    let plain =
+        Transliterator::new().with_arabic(ArabicRomanization::new().with_name_variants(false));
+    assert_eq!(plain.transliterate("Mohamed"), "Mohamed");
+    assert_eq!(plain.transliterate("محمد"), "mhmd");
+}
+
+#[test]
+fn test_arabic_options() {
+    let t = romanizer();
+    assert_eq!(t.transliterate("سَلْمَان"), "salman");
+    assert_eq!(t.transliterate("الرشيد"), "arrshid");
+    assert_eq!(t.transliterate("الرَّشِيد"), "arrashid");
+    assert_eq!(t.transliterate("القمر"), "alqmr");
+    assert_eq!(t.transliterate("Al-Rashid El-Hassan"), "ArRashid AlHasan");
+    assert_eq!(t.transliterate("AL-SHAMI"), "ASHSHAMI");
+    assert_eq!(t.transliterate("Jean-Pierre"), "Jean-Pierre");
+
+    let t = Transliterator::new().with_arabic(
+        ArabicRomanization::new()
+            .with_short_vowels(false)
+            .with_article(false),
+    );
+    assert_eq!(t.transliterate("سَلْمَان"), "slman");
+    assert_eq!(t.transliterate("الرشيد"), "rshid");
+    assert_eq!(t.transliterate("ar-Rashid"), "Rashid");
+
+    // Names of several words go through the name encoder.
+    let encoder = NameEncoder::new(Box::new(Metaphone::new().with_transliterator(romanizer())));
+    assert_eq!(
+        encoder.encode("عبد الرحمن").unwrap(),
+        encoder.encode("Abd Arrahman").unwrap()
+    );
+}
+
+#[test]
+fn test_configured_encoders() {
+    let metaphone = Metaphone::new().with_transliterator(romanizer());
+    assert_eq!(
+        metaphone.config(),
+        "greek=elot_743,arabic=short_vowels+article+names"
+    );
+    let metaphone = Metaphone::new().with_transliterator(
+        Transliterator::new().with_arabic(ArabicRomanization::new().with_short_vowels(false)),
+    );
+    assert_eq!(metaphone.config(), "arabic=article+names");
+
+    // The hyphen is read only as part of an article.
+    assert_eq!(
+        metaphone.encode("Jean-Pierre").unwrap_err(),
+        PhoneticError::NonAlphabetic {
+            position: 4,
+            char: '-'
+        }
+    );
+    assert!(Metaphone::new().encode("Al-Rashid").is_err());
+
+    assert_eq!(
+        Metaphone::new().encode("Σταύρος").unwrap_err(),
+        PhoneticError::UnsupportedScript {
+            position: 0,
+            char: 'Σ'
+        }
+    );
+}
@@ -0,0 +1,98 @@
+use phonetics::{
+    DaitchMokotoff, Metaphone, PhoneticEncoder, PhoneticSimilarity, SimilarityMeasure, Soundex,
+    metaphone::metaphone_similarity,
+};
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < 1e-9,
+        "expected {}, got {}",
+        expected,
+        actual
+    );
+}
+
+#[test]
+fn test_measures_on_codes() {
+    // "jhnsn" and "jhnstn", the Metaphone codes of Johnson and Johnston.
+    assert_close(
+        SimilarityMeasure::EditDistance.score("jhnsn", "jhnstn"),
+        5.0 / 6.0,
+    );
+    assert_close(
+        SimilarityMeasure::CommonPrefix.score("jhnsn", "jhnstn"),
+        4.0 / 6.0,
+    );
+    assert_close(
+        SimilarityMeasure::JaroWinkler.score("jhnsn", "jhnstn"),
+        0.9666666666666667,
+    );
+
+    for measure in [
+        SimilarityMeasure::EditDistance,
+        SimilarityMeasure::JaroWinkler,
+        SimilarityMeasure::CommonPrefix,
+    ] {
+        assert_close(measure.score("sm0", "sm0"), 1.0);
+        assert_close(measure.score("", ""), 1.0);
+        assert_close(measure.score("abc", "xyz"), 0.0);
+    }
+}
+
+#[test]
+fn test_metaphone_similarity() {
+    let m = Metaphone::new();
+    let close = m
+        .similarity("johnson", "Johnston", SimilarityMeasure::JaroWinkler)
+        .unwrap();
+    let far = m
+        .similarity("johnson", "Smith", SimilarityMeasure::JaroWinkler)
+        .unwrap();
+    assert!(!m.compare("johnson", "Johnston").unwrap());
+    assert!(close > 0.9 && far < close);
+
+    assert_close(metaphone_similarity("knight", "night").unwrap(), 1.0);
+    assert!(metaphone_similarity("Sm1th", "Smith").is_err());
+}
+
+#[test]
+fn test_alternative_codes() {
+    // The best pair of alternatives counts.
+    assert_close(SimilarityMeasure::EditDistance.score("ab|xy", "zz|xy"), 1.0);
+
+    let dm = DaitchMokotoff::new();
+    assert_close(dm.similarity("Kohn", "Kohn").unwrap(), 1.0);
+}
+
+#[test]
+fn test_combined_score() {
+    let similarity = PhoneticSimilarity::new();
+    assert_close(similarity.score("Knight", "Night").unwrap(), 1.0);
+
+    let score = similarity.score("johnson", "Johnston").unwrap();
+    assert!(score > 0.8 && score < 1.0);
+
+    let weighted = PhoneticSimilarity::with_weighted_encoders(vec![
+        (Box::new(Soundex::new()), 3.0),
+        (Box::new(Metaphone::new()), 1.0),
+    ])
+    .with_measure(SimilarityMeasure::EditDistance);
+    // Soundex codes "J525" and "J523" score 3/4; Metaphone scores 5/6.
+    assert_close(
+        weighted.score("johnson", "Johnston").unwrap(),
+        (3.0 * 0.75 + 5.0 / 6.0) / 4.0,
+    );
+}
+
+#[test]
+fn test_rank_candidates() {
+    let similarity = PhoneticSimilarity::new();
+    let ranked = similarity
+        .rank("Jonson", &["Smith", "Johnston", "Johnson"])
+        .unwrap();
+    let names: Vec<&str> = ranked.iter().map(|(name, _)| *name).collect();
+
+    assert_eq!(names, vec!["Johnson", "Johnston", "Smith"]);
+    assert!(ranked.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+    assert!(similarity.rank("J0nson", &["Johnson"]).is_err());
+}
@@ -0,0 +1,69 @@
+use phonetics::{PhoneticEncoder, PhoneticError, RefinedSoundex, Soundex};
+
+#[test]
+fn test_soundex() {
+    let s = Soundex::new();
+    assert_eq!(s.encode("Robert").unwrap(), "R163");
+    assert_eq!(s.encode("Rupert").unwrap(), "R163");
+    assert_eq!(s.encode("Rubin").unwrap(), "R150");
+    assert_eq!(s.encode("Honeyman").unwrap(), "H555");
+    assert_eq!(s.encode("Tymczak").unwrap(), "T522");
+    assert_eq!(s.encode("Pfister").unwrap(), "P236");
+    assert_eq!(s.encode("Lee").unwrap(), "L000");
+}
+
+#[test]
+fn test_h_w_rule() {
+    let s = Soundex::new();
+    assert_eq!(s.encode("Ashcraft").unwrap(), "A261");
+    assert_eq!(s.encode("Ashcroft").unwrap(), "A261");
+    assert_eq!(s.encode("Burroughs").unwrap(), "B620");
+}
+
+#[test]
+fn test_soundex_options() {
+    let s = Soundex::new().with_padding(false);
+    assert_eq!(s.encode("Lee").unwrap(), "L");
+    assert_eq!(s.encode("Robert").unwrap(), "R163");
+
+    let s = Soundex::new().with_length(6);
+    assert_eq!(s.encode("Washington").unwrap(), "W25235");
+    assert_eq!(s.encode("Lee").unwrap(), "L00000");
+    assert_eq!(s.max_code_length(), Some(6));
+}
+
+#[test]
+fn test_soundex_compare() {
+    let s = Soundex::new();
+    assert!(s.compare("Robert", "Rupert").unwrap());
+    assert!(!s.compare("Robert", "Rubin").unwrap());
+    assert!(s.compare("", "Rubin").is_err());
+    assert!(s.encode("R2D2").is_err());
+}
+
+#[test]
+fn test_diacritics() {
+    let s = Soundex::new();
+    assert_eq!(s.encode("Éé").unwrap(), "E000");
+    assert_eq!(
+        s.encode("ƿ").unwrap_err(),
+        PhoneticError::UnsupportedScript {
+            position: 0,
+            char: 'ƿ'
+        }
+    );
+}
+
+#[test]
+fn test_refined_soundex() {
+    let r = RefinedSoundex::new();
+    assert_eq!(r.encode("Braz").unwrap(), "B1905");
+    assert_eq!(r.encode("Caren").unwrap(), "C30908");
+    assert_eq!(r.encode("Hayers").unwrap(), "H093");
+    assert_eq!(r.encode("Lambert").unwrap(), "L7081096");
+    assert_eq!(r.encode("Nolton").unwrap(), "N807608");
+    assert!(r.compare("Caren", "Carren").unwrap());
+
+    let r = RefinedSoundex::new().with_max_length(4);
+    assert_eq!(r.encode("Lambert").unwrap(), "L708");
+}
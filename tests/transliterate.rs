@@ -0,0 +1,92 @@
+use phonetics::{ExplainStage, Metaphone, Nysiis, PhoneticEncoder, Transliterator};
+
+#[test]
+fn test_diacritics_and_letters() {
+    let t = Transliterator::new();
+    assert_eq!(t.transliterate("Müller"), "Muller");
+    assert_eq!(t.transliterate("José Ñúñez"), "Jose Nunez");
+    // Decomposed input folds the same way as precomposed input.
+    assert_eq!(t.transliterate("Mu\u{0308}ller"), "Muller");
+
+    assert_eq!(t.transliterate("Ærøskøbing"), "AEroskobing");
+    assert_eq!(t.transliterate("Œuvre Straße"), "OEuvre Strasse");
+    assert_eq!(t.transliterate("Łódź Đorđe"), "Lodz Dorde");
+    assert_eq!(t.transliterate("ﬁnn"), "finn");
+    assert_eq!(t.transliterate("Smith"), "Smith");
+}
+
+#[test]
+fn test_custom_mappings() {
+    let german = Transliterator::new().with_table(&[('ä', "ae"), ('ö', "oe"), ('ü', "ue")]);
+    assert_eq!(german.transliterate("Müller"), "Mueller");
+    assert_eq!(german.transliterate("Mu\u{0308}ller"), "Mueller");
+    assert_eq!(german.transliterate("Björk"), "Bjoerk");
+
+    let t = Transliterator::new().with_mapping('ß', "sz");
+    assert_eq!(t.transliterate("Straße"), "Strasze");
+}
+
+#[test]
+fn test_encoders_fold_accents() {
+    let metaphone = Metaphone::new();
+    assert_eq!(metaphone.encode("Müller").unwrap(), "mlr");
+    assert_eq!(metaphone.encode("Strauß").unwrap(), "strs");
+    assert!(metaphone.compare("Jérôme", "Jerome").unwrap());
+
+    let nysiis = Nysiis::new();
+    assert_eq!(nysiis.encode("Müller"), nysiis.encode("Muller"));
+    assert_eq!(nysiis.encode("Ødegård"), "ODAGAD");
+    // Once spelled out, "æ" leaves ASCII letters to encode.
+    assert_eq!(nysiis.try_encode("Ææ").unwrap(), "A");
+}
+
+#[test]
+fn test_decomposed_input() {
+    let metaphone = Metaphone::new();
+    assert_eq!(metaphone.encode("Mu\u{0308}ller").unwrap(), "mlr");
+    assert_eq!(
+        metaphone.encode("Je\u{0301}ro\u{0302}me").unwrap(),
+        metaphone.encode("Jérôme").unwrap()
+    );
+
+    let nysiis = Nysiis::new();
+    assert_eq!(nysiis.encode("Mu\u{0308}ller"), nysiis.encode("Müller"));
+    assert_eq!(nysiis.try_encode("Mu\u{0308}ller").unwrap(), "MALAR");
+}
+
+#[test]
+fn test_configured_encoders() {
+    let german = Transliterator::new().with_table(&[('ü', "ue")]);
+
+    let metaphone = Metaphone::new().with_transliterator(german.clone());
+    assert!(metaphone.compare("Müller", "Mueller").unwrap());
+    assert_eq!(metaphone.config(), "transliterate=ü:ue");
+    assert_eq!(Metaphone::new().config(), "");
+
+    // Separators inside mappings are escaped, so distinct mappings give distinct configs.
+    let t = Transliterator::new()
+        .with_mapping(',', "x=y")
+        .with_mapping('ü', "ue");
+    assert_eq!(
+        Metaphone::new().with_transliterator(t).config(),
+        "transliterate=\\,:x\\=y;ü:ue"
+    );
+
+    let nysiis = Nysiis::new().with_transliterator(german);
+    assert_eq!(nysiis.encode("Grün"), nysiis.encode("Gruen"));
+    assert_eq!(
+        nysiis.config(),
+        "variant=extended,max_length=6,transliterate=ü:ue"
+    );
+}
+
+#[test]
+fn test_explained_transliteration() {
+    let explanation = Metaphone::new().encode_explained("Weiß").unwrap();
+    let step = &explanation.steps[0];
+
+    assert_eq!(step.stage, ExplainStage::Preprocess);
+    assert_eq!(step.rule, "transliterate");
+    assert_eq!(step.span, 3..4);
+    assert_eq!((step.input.as_str(), step.emitted.as_str()), ("ß", "ss"));
+}